@@ -0,0 +1,47 @@
+use audio_channel_buffer::ChannelBuffer;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const FRAMES: usize = 4096;
+
+fn bench_channels<const CHANNELS: usize>(c: &mut Criterion) {
+    let mut src = ChannelBuffer::<f32, CHANNELS>::new(FRAMES);
+    src.as_mut()
+        .fill_from_fn(|ch, frame| (ch * FRAMES + frame) as f32);
+
+    let mut interleaved = vec![0.0; FRAMES * CHANNELS];
+    let mut dst = ChannelBuffer::<f32, CHANNELS>::new(FRAMES);
+
+    let mut group = c.benchmark_group("interleave");
+    group.bench_with_input(
+        BenchmarkId::new("interleave_from_at", CHANNELS),
+        &(),
+        |b, _| b.iter(|| black_box(&src).interleave_from_at(0, black_box(&mut interleaved))),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("deinterleave_into_at", CHANNELS),
+        &(),
+        |b, _| b.iter(|| dst.deinterleave_into_at(black_box(&interleaved), 0)),
+    );
+    group.bench_with_input(
+        BenchmarkId::new("from_interleaved_vec_in_place", CHANNELS),
+        &(),
+        |b, _| {
+            b.iter_batched(
+                || interleaved.clone(),
+                ChannelBuffer::<f32, CHANNELS>::from_interleaved_vec_in_place,
+                criterion::BatchSize::LargeInput,
+            )
+        },
+    );
+    group.finish();
+}
+
+fn benches(c: &mut Criterion) {
+    bench_channels::<1>(c);
+    bench_channels::<2>(c);
+    bench_channels::<6>(c);
+    bench_channels::<8>(c);
+}
+
+criterion_group!(interleave, benches);
+criterion_main!(interleave);
@@ -0,0 +1,186 @@
+use core::f32::consts::PI;
+
+use crate::ChannelBufferRefMut;
+
+/// The normalized coefficients of a [`Biquad`] filter (with `a0 == 1.0`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BiquadCoeffs {
+    pub b0: f32,
+    pub b1: f32,
+    pub b2: f32,
+    pub a1: f32,
+    pub a2: f32,
+}
+
+impl BiquadCoeffs {
+    /// Coefficients which pass the signal through unchanged.
+    pub const IDENTITY: Self = Self {
+        b0: 1.0,
+        b1: 0.0,
+        b2: 0.0,
+        a1: 0.0,
+        a2: 0.0,
+    };
+
+    /// Create coefficients for a second-order lowpass filter.
+    ///
+    /// * `freq` - The cutoff frequency in hertz.
+    /// * `q` - The quality factor of the filter (`0.7071` for a Butterworth response).
+    /// * `sample_rate` - The sample rate in hertz.
+    pub fn lowpass(freq: f32, q: f32, sample_rate: f32) -> Self {
+        let (cos_w0, alpha) = Self::cos_w0_and_alpha(freq, q, sample_rate);
+
+        Self::normalize(
+            (1.0 - cos_w0) * 0.5,
+            1.0 - cos_w0,
+            (1.0 - cos_w0) * 0.5,
+            1.0 + alpha,
+            -2.0 * cos_w0,
+            1.0 - alpha,
+        )
+    }
+
+    /// Create coefficients for a second-order highpass filter.
+    ///
+    /// * `freq` - The cutoff frequency in hertz.
+    /// * `q` - The quality factor of the filter (`0.7071` for a Butterworth response).
+    /// * `sample_rate` - The sample rate in hertz.
+    pub fn highpass(freq: f32, q: f32, sample_rate: f32) -> Self {
+        let (cos_w0, alpha) = Self::cos_w0_and_alpha(freq, q, sample_rate);
+
+        Self::normalize(
+            (1.0 + cos_w0) * 0.5,
+            -(1.0 + cos_w0),
+            (1.0 + cos_w0) * 0.5,
+            1.0 + alpha,
+            -2.0 * cos_w0,
+            1.0 - alpha,
+        )
+    }
+
+    /// Create coefficients for a peaking (bell) equalizer filter.
+    ///
+    /// * `freq` - The center frequency in hertz.
+    /// * `q` - The quality factor of the filter.
+    /// * `gain_db` - The gain at the center frequency in decibels.
+    /// * `sample_rate` - The sample rate in hertz.
+    pub fn peak(freq: f32, q: f32, gain_db: f32, sample_rate: f32) -> Self {
+        let (cos_w0, alpha) = Self::cos_w0_and_alpha(freq, q, sample_rate);
+        let a = libm::powf(10.0, gain_db / 40.0);
+
+        Self::normalize(
+            1.0 + alpha * a,
+            -2.0 * cos_w0,
+            1.0 - alpha * a,
+            1.0 + alpha / a,
+            -2.0 * cos_w0,
+            1.0 - alpha / a,
+        )
+    }
+
+    fn cos_w0_and_alpha(freq: f32, q: f32, sample_rate: f32) -> (f32, f32) {
+        let w0 = 2.0 * PI * freq / sample_rate;
+
+        (libm::cosf(w0), libm::sinf(w0) / (2.0 * q))
+    }
+
+    fn normalize(b0: f32, b1: f32, b2: f32, a0: f32, a1: f32, a2: f32) -> Self {
+        let a0_recip = a0.recip();
+
+        Self {
+            b0: b0 * a0_recip,
+            b1: b1 * a0_recip,
+            b2: b2 * a0_recip,
+            a1: a1 * a0_recip,
+            a2: a2 * a0_recip,
+        }
+    }
+}
+
+impl Default for BiquadCoeffs {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// The state of a single channel of a [`Biquad`] filter.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct BiquadState {
+    pub z1: f32,
+    pub z2: f32,
+}
+
+impl BiquadState {
+    /// Process a single sample using the transposed direct form II structure.
+    #[inline(always)]
+    pub fn tick(&mut self, coeffs: &BiquadCoeffs, x: f32) -> f32 {
+        let y = coeffs.b0 * x + self.z1;
+
+        self.z1 = coeffs.b1 * x - coeffs.a1 * y + self.z2;
+        self.z2 = coeffs.b2 * x - coeffs.a2 * y;
+
+        y
+    }
+}
+
+/// A second-order IIR filter which keeps an independent state for each of `CHANNELS`
+/// channels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Biquad<const CHANNELS: usize> {
+    pub coeffs: BiquadCoeffs,
+    pub states: [BiquadState; CHANNELS],
+}
+
+impl<const CHANNELS: usize> Biquad<CHANNELS> {
+    /// Create a new [`Biquad`] filter with the given coefficients.
+    pub fn new(coeffs: BiquadCoeffs) -> Self {
+        Self {
+            coeffs,
+            states: [BiquadState::default(); CHANNELS],
+        }
+    }
+
+    /// Create a new second-order lowpass [`Biquad`] filter.
+    ///
+    /// See [`BiquadCoeffs::lowpass`].
+    pub fn lowpass(freq: f32, q: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadCoeffs::lowpass(freq, q, sample_rate))
+    }
+
+    /// Create a new second-order highpass [`Biquad`] filter.
+    ///
+    /// See [`BiquadCoeffs::highpass`].
+    pub fn highpass(freq: f32, q: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadCoeffs::highpass(freq, q, sample_rate))
+    }
+
+    /// Create a new peaking (bell) equalizer [`Biquad`] filter.
+    ///
+    /// See [`BiquadCoeffs::peak`].
+    pub fn peak(freq: f32, q: f32, gain_db: f32, sample_rate: f32) -> Self {
+        Self::new(BiquadCoeffs::peak(freq, q, gain_db, sample_rate))
+    }
+
+    /// Reset the state of every channel.
+    pub fn reset(&mut self) {
+        self.states = [BiquadState::default(); CHANNELS];
+    }
+
+    /// Filter every channel of `buf` in place.
+    ///
+    /// The state of each channel is kept between calls, so consecutive blocks of
+    /// audio are filtered seamlessly.
+    pub fn process(&mut self, buf: &mut ChannelBufferRefMut<f32, CHANNELS>) {
+        for (ch, state) in buf.as_mut_slices().into_iter().zip(self.states.iter_mut()) {
+            for s in ch.iter_mut() {
+                *s = state.tick(&self.coeffs, *s);
+            }
+        }
+    }
+}
+
+impl<const CHANNELS: usize> Default for Biquad<CHANNELS> {
+    fn default() -> Self {
+        Self::new(BiquadCoeffs::IDENTITY)
+    }
+}
@@ -1,8 +1,33 @@
+use alloc::boxed::Box;
 use alloc::vec::Vec;
-use core::ops::{Index, IndexMut, Range};
+use core::mem::MaybeUninit;
+use core::ops::{AddAssign, Index, IndexMut, MulAssign, Range};
 use core::pin::Pin;
 
-use crate::{ChannelBufferRef, ChannelBufferRefMut};
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+#[cfg(feature = "cpal")]
+use dasp_sample::{FromSample, Sample};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator};
+
+#[cfg(feature = "convert")]
+use crate::convert::SampleConvert;
+#[cfg(feature = "float")]
+use crate::dsp::{
+    apply_gain_slice, fade_in_slice, fade_out_slice, flush_denormals_slice, peak_slice,
+    soft_clip_fast_slice, soft_clip_slice, tpdf_dither_slice, Float, PanLaw,
+};
+#[cfg(feature = "prefetch")]
+use crate::prefetch::prefetch_read;
+use crate::util::{
+    check_range, clamp_slice, clamped_frames, copy_reversed, deinterleave, interleave,
+    resize_planar, shift_slice_left, transpose_in_place,
+};
+use crate::{
+    Anchor, AsChannelBufferRef, BufferShape, CapacityError, ChannelBufferRef, ChannelBufferRefMut,
+    FramesMut, LayoutError, RangeError, ScratchBuffer, ShapeMismatch, SumOfSquares, TransposedView,
+};
 
 /// A memory-efficient buffer of samples with a fixed compile-time number of channels
 /// each with a fixed runtime number of frames (samples in a single channel of audio).
@@ -21,6 +46,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     };
 
     /// Create an empty [`ChannelBuffer`] with no allocated capacity.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -40,6 +66,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     /// of audio).
     ///
     /// All data will be initialized with the default value.
+    #[allow(clippy::let_unit_value)]
     pub fn new(frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -71,6 +98,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     ///
     /// # Safety
     /// Any data must be initialized before reading.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_uninit(frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -94,6 +122,324 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
+    /// Create a new [`ChannelBuffer`] with the given number of frames, where every
+    /// sample in channel `i` is set to `values[i]`.
+    ///
+    /// This is useful for building test signals with easily checked contents.
+    pub fn from_channel_values(frames: usize, values: [T; CHANNELS]) -> Self {
+        let mut buf = Self::new(frames);
+        for (ch, value) in buf.as_mut_slices().into_iter().zip(values) {
+            ch.fill(value);
+        }
+        buf
+    }
+
+    /// Create a new [`ChannelBuffer`] which uses the given `Vec` as its data, without
+    /// copying.
+    ///
+    /// The data must be in planar layout, with each channel stored one after the other.
+    /// The number of frames will be `data.len() / CHANNELS`.
+    ///
+    /// Returns an error if `data.len()` is not a multiple of `CHANNELS`.
+    #[allow(clippy::let_unit_value)]
+    pub fn try_from_vec(data: Vec<T>) -> Result<Self, LayoutError> {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        let frames = data.len() / CHANNELS;
+
+        if frames * CHANNELS != data.len() {
+            return Err(LayoutError {
+                len: data.len(),
+                channels: CHANNELS,
+            });
+        }
+
+        let mut data = Pin::new(data);
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        let offsets = unsafe { core::array::from_fn(|ch_i| data.as_mut_ptr().add(ch_i * frames)) };
+
+        Ok(Self {
+            data,
+            offsets,
+            frames,
+        })
+    }
+
+    /// Create a new [`ChannelBuffer`] from a `Vec` of interleaved samples, reusing the
+    /// `Vec` as the planar storage of the buffer.
+    ///
+    /// The samples are transposed from interleaved to planar layout in place, which
+    /// takes `O(n)` time for `n` samples and only allocates one bit per sample to track
+    /// the transpose, instead of a second copy of the data. Any trailing partial frame
+    /// is discarded.
+    pub fn from_interleaved_vec_in_place(mut data: Vec<T>) -> Self {
+        let frames = data.len() / CHANNELS;
+        data.truncate(frames * CHANNELS);
+
+        transpose_in_place(&mut data, frames, CHANNELS);
+
+        Self::from_raw_parts(data, frames)
+    }
+
+    /// Create a new [`ChannelBuffer`] from a `Vec` and a number of frames previously
+    /// returned by [`ChannelBuffer::into_raw_parts`], without copying.
+    ///
+    /// The data must be in planar layout, with each channel stored one after the other.
+    /// Any samples after the first `frames * CHANNELS` samples are dropped without
+    /// reallocating, so the capacity of the `Vec` is kept.
+    ///
+    /// # Panics
+    /// Panics if `frames * CHANNELS` overflows or if `data.len() < frames * CHANNELS`.
+    #[allow(clippy::let_unit_value)]
+    pub fn from_raw_parts(mut data: Vec<T>, frames: usize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        assert!(frames
+            .checked_mul(CHANNELS)
+            .is_some_and(|n| data.len() >= n));
+
+        data.truncate(frames * CHANNELS);
+
+        let mut data = Pin::new(data);
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        let offsets = unsafe { core::array::from_fn(|ch_i| data.as_mut_ptr().add(ch_i * frames)) };
+
+        Self {
+            data,
+            offsets,
+            frames,
+        }
+    }
+
+    /// Consume this buffer and return the backing `Vec` along with the number of frames
+    /// in each channel.
+    ///
+    /// This is the only way to move the allocation out of the buffer, since the buffer
+    /// holds pointers into it. Use [`ChannelBuffer::from_raw_parts`] to move it back
+    /// into a buffer.
+    pub fn into_raw_parts(self) -> (Vec<T>, usize) {
+        (Pin::into_inner(self.data), self.frames)
+    }
+
+    /// Consume this buffer and return its samples as a boxed slice in planar layout,
+    /// with each channel stored one after the other.
+    ///
+    /// Unlike converting into a `Vec`, this drops any spare capacity, along with any
+    /// trailing samples beyond the `CHANNELS * self.frames()` samples of this buffer.
+    /// This may reallocate.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        let (mut data, frames) = self.into_raw_parts();
+        data.truncate(frames * CHANNELS);
+        data.into_boxed_slice()
+    }
+
+    /// Consume this buffer and get an iterator over each frame, with the samples from
+    /// every channel gathered into an owned array.
+    ///
+    /// Unlike [`ChannelBufferRef::interleaved_frames`], this takes ownership of the
+    /// buffer, which is freed once the iterator is dropped.
+    pub fn into_frames_iter(self) -> impl ExactSizeIterator<Item = [T; CHANNELS]> {
+        let (data, frames) = self.into_raw_parts();
+
+        (0..frames).map(move |frame_i| core::array::from_fn(|ch_i| data[ch_i * frames + frame_i]))
+    }
+
+    /// Consume this buffer and deliberately leak its allocation, returning a mutable
+    /// reference to it which is valid for the rest of the program.
+    ///
+    /// This is intended for FFI scenarios where the buffer must outlive its Rust-side
+    /// owner, such as handing a host a channel pointer array for the lifetime of a
+    /// plugin instance. The allocation is never freed unless it is passed back to
+    /// [`ChannelBuffer::reclaim`].
+    ///
+    /// Any unused capacity is released before the allocation is leaked.
+    pub fn leak(self) -> ChannelBufferRefMut<'static, T, CHANNELS>
+    where
+        T: 'static,
+    {
+        let frames = self.frames;
+        let data: &'static mut [T] = Box::leak(Pin::into_inner(self.data).into_boxed_slice());
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the leaked slice.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        let offsets = unsafe { core::array::from_fn(|ch_i| data.as_mut_ptr().add(ch_i * frames)) };
+
+        // SAFETY:
+        // * The leaked slice has a length of at least `frames * CHANNELS`.
+        // * The leaked slice is never freed, so it is valid for `'static`.
+        unsafe { ChannelBufferRefMut::from_raw(data, offsets, frames) }
+    }
+
+    /// Take back ownership of a buffer previously leaked with [`ChannelBuffer::leak`],
+    /// so that its allocation is freed when the returned buffer is dropped.
+    ///
+    /// # Safety
+    /// `buffer` must be the exact value returned by [`ChannelBuffer::leak`] (with the
+    /// same type `T` and number of `CHANNELS`), and it must not have been reclaimed
+    /// already. Any pointers into the buffer which were handed out (e.g. to C code)
+    /// must no longer be used.
+    pub unsafe fn reclaim(buffer: ChannelBufferRefMut<'static, T, CHANNELS>) -> Self {
+        let frames = buffer.frames();
+        let data: &'static mut [T] = buffer.into();
+
+        // SAFETY: The caller guarantees that `data` is the entire slice leaked from a
+        // boxed slice in `leak`, so it can be turned back into that box.
+        let data = unsafe { Box::from_raw(data as *mut [T]) }.into_vec();
+
+        Self::from_raw_parts(data, frames)
+    }
+
+    /// Create a new [`ChannelBuffer`] from an iterator of interleaved samples.
+    ///
+    /// The samples are first collected into a temporary `Vec`, so prefer
+    /// [`ChannelBuffer::from_interleaved_iter_with_frames`] when the number of frames
+    /// is known ahead of time. Any trailing partial frame will be discarded.
+    pub fn from_interleaved_iter(iter: impl IntoIterator<Item = T>) -> Self {
+        let interleaved: Vec<T> = iter.into_iter().collect();
+
+        let mut buf = Self::new(interleaved.len() / CHANNELS);
+        deinterleave(&interleaved, &mut buf.as_mut_slices());
+
+        buf
+    }
+
+    /// Create a new [`ChannelBuffer`] with the given number of `frames`, filled from an
+    /// iterator of interleaved samples.
+    ///
+    /// Any samples after `frames` frames are ignored. If the iterator yields fewer
+    /// than `frames` complete frames, then the trailing partial frame is discarded and
+    /// the remaining frames are initialized with the default value.
+    pub fn from_interleaved_iter_with_frames(
+        iter: impl IntoIterator<Item = T>,
+        frames: usize,
+    ) -> Self {
+        let mut buf = Self::new(frames);
+        let mut iter = iter.into_iter();
+
+        let mut channels = buf.as_mut_slices();
+        'frames: for frame_i in 0..frames {
+            let mut frame = [T::default(); CHANNELS];
+            for s in frame.iter_mut() {
+                let Some(v) = iter.next() else {
+                    break 'frames;
+                };
+                *s = v;
+            }
+
+            for (ch, s) in channels.iter_mut().zip(frame) {
+                ch[frame_i] = s;
+            }
+        }
+
+        buf
+    }
+
+    /// Change the number of frames in each channel without allocating.
+    ///
+    /// The channels are re-packed in place, so each channel keeps its data up to the
+    /// new number of frames. When growing, the new frames at the end of each channel
+    /// are filled with the default value.
+    ///
+    /// This is realtime-safe. Returns an error if `frames * CHANNELS` is greater than
+    /// the allocated capacity of the buffer or overflows, in which case the buffer is
+    /// left unchanged.
+    pub fn set_frames_within_capacity(&mut self, frames: usize) -> Result<(), CapacityError> {
+        let old_frames = self.frames;
+        let required = frames.checked_mul(CHANNELS);
+
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+
+        let required = match required {
+            Some(required) if required <= data.capacity() => required,
+            _ => {
+                let capacity = data.capacity();
+                self.data = Pin::new(data);
+                return Err(CapacityError {
+                    required: required.unwrap_or(usize::MAX),
+                    capacity,
+                });
+            }
+        };
+
+        data.truncate(old_frames * CHANNELS);
+
+        if frames < old_frames {
+            // Move the channels inward, starting with the first channel so that no
+            // channel is overwritten before it has been moved.
+            for ch_i in 1..CHANNELS {
+                let start = ch_i * old_frames;
+                data.copy_within(start..start + frames, ch_i * frames);
+            }
+            data.truncate(required);
+        } else if frames > old_frames {
+            // This will not allocate since we have checked the capacity above.
+            data.resize(required, T::default());
+
+            // Move the channels outward, starting with the last channel so that no
+            // channel is overwritten before it has been moved.
+            for ch_i in (0..CHANNELS).rev() {
+                let start = ch_i * old_frames;
+                data.copy_within(start..start + old_frames, ch_i * frames);
+                data[ch_i * frames + old_frames..(ch_i + 1) * frames].fill(T::default());
+            }
+        }
+
+        self.data = Pin::new(data);
+        self.frames = frames;
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        self.offsets =
+            unsafe { core::array::from_fn(|ch_i| self.data.as_mut_ptr().add(ch_i * frames)) };
+
+        Ok(())
+    }
+
+    /// Change the number of frames in each channel, keeping the samples at the given
+    /// `anchor` end of each channel.
+    ///
+    /// With [`Anchor::Start`], frames are added or removed at the end of each channel.
+    /// With [`Anchor::End`], frames are added or removed at the start of each channel,
+    /// which is useful for things like lookahead and latency compensation where the
+    /// most recent samples must be kept. Any new frames are filled with the default
+    /// value.
+    ///
+    /// This will allocate if the new size is larger than the allocated capacity.
+    ///
+    /// # Panics
+    /// Panics if the new size overflows or exceeds the maximum capacity of a `Vec`. The
+    /// buffer is left unchanged in that case.
+    pub fn resize_frames_with(&mut self, frames: usize, anchor: Anchor) {
+        let required = frames.checked_mul(CHANNELS).expect("capacity overflow");
+
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+
+        data.truncate(self.frames * CHANNELS);
+        if let Err(e) = data.try_reserve_exact(required.saturating_sub(data.len())) {
+            // Put the data back before panicking so that the offsets stay valid.
+            self.data = Pin::new(data);
+            panic!("{e}");
+        }
+        resize_planar(&mut data, CHANNELS, self.frames, frames, anchor);
+
+        self.data = Pin::new(data);
+        self.frames = frames;
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        self.offsets =
+            unsafe { core::array::from_fn(|ch_i| self.data.as_mut_ptr().add(ch_i * frames)) };
+    }
+
     /// The number of channels in this buffer.
     pub fn channels(&self) -> usize {
         CHANNELS
@@ -105,6 +451,295 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         self.frames
     }
 
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.as_ref().first_nonzero_frame()
+    }
+
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.as_ref().last_nonzero_frame()
+    }
+
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        self.as_ref().first_frame_above(threshold)
+    }
+
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        self.as_ref().last_frame_above(threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        self.as_ref().frame_energy_into(dst)
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Get the `(min, max)` value of the samples in each channel.
+    ///
+    /// This is useful for scaling the view of a waveform display. Each channel is
+    /// scanned in a single pass. If this buffer is empty, then both values are the
+    /// default value.
+    pub fn value_range(&self) -> [(T, T); CHANNELS]
+    where
+        T: PartialOrd,
+    {
+        self.as_ref().value_range()
+    }
+
+    /// Get the `(min, max)` value of the samples across all channels.
+    ///
+    /// If this buffer is empty, then both values are the default value.
+    pub fn value_range_all(&self) -> (T, T)
+    where
+        T: PartialOrd,
+    {
+        self.as_ref().value_range_all()
+    }
+
+    /// Get the largest absolute value of the samples in each channel.
+    ///
+    /// This is the per-channel version of `peak()`. Each value is `0` if this buffer
+    /// is empty.
+    #[cfg(feature = "float")]
+    pub fn abs_max(&self) -> [T; CHANNELS]
+    where
+        T: Float,
+    {
+        self.as_ref().abs_max()
+    }
+
+    /// Get the sum of the squares of every sample in each channel.
+    ///
+    /// See [`SumOfSquares`] for the type the squares are accumulated in for each
+    /// sample type.
+    pub fn sum_of_squares(&self) -> [T::Accumulator; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_ref().sum_of_squares()
+    }
+
+    /// Get the root mean square level of each channel.
+    ///
+    /// The squares are accumulated in a wider type (see [`SumOfSquares`]), so this will
+    /// not overflow for loud integer signals. Returns `0` for each channel if this
+    /// buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn rms(&self) -> [f64; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_ref().rms()
+    }
+
+    /// Copy the interleaved samples in `interleaved` into the frames of this buffer
+    /// starting at `dst_start_frame`.
+    ///
+    /// This is useful for reading from an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in this buffer will be copied, and any
+    /// trailing partial frame in `interleaved` will be ignored. Returns the number of
+    /// frames that were copied.
+    pub fn deinterleave_into_at(&mut self, interleaved: &[T], dst_start_frame: usize) -> usize {
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(interleaved.len() / CHANNELS);
+
+        deinterleave(
+            &interleaved[..frames * CHANNELS],
+            &mut self.as_mut_slices_with_range(start..start + frames),
+        );
+
+        frames
+    }
+
+    /// Copy the samples of the single channel of `interleaved` into this buffer,
+    /// treating them as interleaved frames.
+    ///
+    /// This is the same as [`deinterleave_into_at`](Self::deinterleave_into_at) with a
+    /// `dst_start_frame` of `0`, for pipelines which pass interleaved data around in a
+    /// mono buffer. Returns the number of frames that were copied.
+    pub fn deinterleave_from_mono(&mut self, interleaved: impl AsChannelBufferRef<T, 1>) -> usize {
+        let interleaved = interleaved.as_channel_buffer_ref();
+        let [src] = interleaved.as_slices();
+
+        self.deinterleave_into_at(src, 0)
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / CHANNELS);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * CHANNELS],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
+    /// Resample the contents of this buffer into `dst` by calling `f(src_channel,
+    /// dst_channel)` once for each pair of channels, in channel order.
+    ///
+    /// This lets an external resampler be plugged in without having to write the
+    /// per-channel loop. The slices passed to `f` have lengths of `self.frames()` and
+    /// `dst.frames()` respectively, and `f` is responsible for any state it needs to
+    /// keep between channels.
+    pub fn resample_each_channel_into(
+        &self,
+        dst: &mut ChannelBufferRefMut<T, CHANNELS>,
+        f: impl FnMut(&[T], &mut [T]),
+    ) {
+        self.as_ref().resample_each_channel_into(dst, f)
+    }
+
+    /// Interleave the contents of this buffer into `scratch` and return a view which
+    /// gives contiguous access to every frame.
+    ///
+    /// This trades a single transpose pass for fast frame access, which pays off when
+    /// an algorithm makes many frame-major passes over the buffer. For a single pass,
+    /// prefer `interleaved_frames()`.
+    ///
+    /// Only the frames that fit in `scratch` will be transposed. This method never
+    /// allocates.
+    pub fn transpose_into_interleaved_scratch<'s>(
+        &self,
+        scratch: &'s mut ScratchBuffer<T>,
+    ) -> TransposedView<'s, T, CHANNELS> {
+        self.as_ref().transpose_into_interleaved_scratch(scratch)
+    }
+
+    /// Resample the contents of this buffer into `dst` using linear interpolation,
+    /// stretching or squashing `self.frames()` frames to fit exactly into `dst.frames()`
+    /// frames.
+    ///
+    /// Every channel is read from the same fractional position, so the channels stay
+    /// phase-aligned. This is intended for things like UI scrubbing previews and test
+    /// fixtures rather than high-quality sample rate conversion.
+    ///
+    /// If this buffer is empty, then `dst` is filled with zeros.
+    #[cfg(feature = "float")]
+    pub fn resample_linear_into(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>)
+    where
+        T: Float,
+    {
+        self.as_ref().resample_linear_into(dst)
+    }
+
+    /// Mix the channels of this buffer into the channels of `dst` using a coefficient
+    /// matrix, such that `dst[o][f] = Σ_i matrix[o][i] * self[i][f]`.
+    ///
+    /// Each row of `matrix` holds the gains of every input channel for one output
+    /// channel. This is the general primitive behind downmixing, upmixing, and panning
+    /// (e.g. a surround-to-stereo downmix with ITU coefficients).
+    ///
+    /// This overwrites the contents of `dst` rather than adding to them.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    #[cfg(feature = "float")]
+    pub fn apply_mix_matrix_into<const OUT: usize>(
+        &self,
+        dst: &mut ChannelBufferRefMut<T, OUT>,
+        matrix: &[[T; CHANNELS]; OUT],
+    ) where
+        T: Float,
+    {
+        self.as_ref().apply_mix_matrix_into(dst, matrix)
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -120,6 +755,18 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -138,6 +785,21 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -153,6 +815,18 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
+    /// Get a mutable reference to the given range of frames in the channel at `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range_mut(&mut self, index: usize, range: Range<usize>) -> Option<&mut [T]> {
+        let ch = self.channel_mut(index)?;
+        let range = clamped_frames(range, ch.len());
+        Some(&mut ch[range])
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -173,6 +847,56 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         core::slice::from_raw_parts_mut(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get a mutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRefMut`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer_mut(&mut self, index: usize) -> Option<ChannelBufferRefMut<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel_mut(index)?;
+        let ptr = ch.as_mut_ptr();
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRefMut::from_raw(ch, [ptr], frames)) }
+    }
+
+    /// Get mutable references to the channels at each of the given `indices` at once.
+    /// Each slice will have a length of `self.frames()`.
+    ///
+    /// Returns `None` if any index is out of bounds or if any index appears more than
+    /// once.
+    #[inline]
+    pub fn channels_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut [T]; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.offsets.len() || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * We have checked above that every index is within bounds and that no index
+        // appears more than once, so none of the returned slices overlap.
+        // * The Vec is pinned and cannot be moved, so the pointers are valid for the lifetime
+        // of the struct.
+        // * `self` is borrowed as mutable, so all mutability rules are being upheld.
+        unsafe {
+            Some(core::array::from_fn(|i| {
+                core::slice::from_raw_parts_mut(
+                    *self.offsets.get_unchecked(indices[i]),
+                    self.frames,
+                )
+            }))
+        }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> [&[T]; CHANNELS] {
@@ -264,7 +988,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> [&[T]; CHANNELS] {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         // SAFETY:
         //
@@ -284,6 +1008,19 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`ChannelBuffer::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<[&[T]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
     /// Get all channels as mutable slices in the given range.
     ///
     /// If all or part of the range falls out of bounds, then only the part that falls
@@ -291,7 +1028,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     #[inline]
     pub fn as_mut_slices_with_range(&mut self, range: Range<usize>) -> [&mut [T]; CHANNELS] {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         // SAFETY:
         //
@@ -313,15 +1050,353 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
-    /// Get the entire contents of the buffer as a single immutable slice.
-    pub fn raw(&self) -> &[T] {
-        &self.data
+    /// Get all channels as mutable slices in the given range.
+    ///
+    /// Unlike [`ChannelBuffer::as_mut_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_mut_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<[&mut [T]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_mut_slices_with_range(range))
     }
 
-    /// Get the entire contents of the buffer as a single mutable slice.
-    pub fn raw_mut(&mut self) -> &mut [T] {
-        &mut self.data
-    }
+    /// Get all channels in the given range as mutable slices of possibly-uninitialized
+    /// samples, such as when filling part of a buffer created with
+    /// [`ChannelBuffer::new_uninit`].
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// # Safety
+    /// The samples in the returned slices are treated as initialized by every other
+    /// method, so the caller must not write uninitialized values into them (e.g.
+    /// `MaybeUninit::uninit()`).
+    #[inline]
+    pub unsafe fn as_maybe_uninit_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> [&mut [MaybeUninit<T>]; CHANNELS] {
+        let range = clamped_frames(range, self.frames);
+
+        // SAFETY:
+        // * The constructors ensure that every pointer points to a region of `frames`
+        // samples in the data vec, and we have constrained the range above.
+        // * `MaybeUninit<T>` has the same layout as `T`, and the slices are created
+        // directly from the pointers so that no possibly-uninitialized samples are
+        // referenced as `T`.
+        // * `self` is borrowed as mutable, and none of these slices overlap, so all
+        // mutability rules are being upheld.
+        // * The caller upholds that no uninitialized values are written into the slices.
+        self.offsets.map(|ptr| unsafe {
+            core::slice::from_raw_parts_mut(
+                ptr.add(range.start).cast::<MaybeUninit<T>>(),
+                range.end - range.start,
+            )
+        })
+    }
+
+    /// Get all channels in the given range as mutable slices of possibly-uninitialized
+    /// samples.
+    ///
+    /// Unlike [`ChannelBuffer::as_maybe_uninit_slices_with_range`], this returns an
+    /// error if the range is invalid or if any part of it falls out of bounds.
+    ///
+    /// # Safety
+    /// The samples in the returned slices are treated as initialized by every other
+    /// method, so the caller must not write uninitialized values into them (e.g.
+    /// `MaybeUninit::uninit()`).
+    #[inline]
+    pub unsafe fn try_as_maybe_uninit_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<[&mut [MaybeUninit<T>]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_maybe_uninit_slices_with_range(range))
+    }
+
+    /// Convert the channel at index `src_ch` into the sample type `U` and write it into
+    /// the channel at index `dst_ch` of `dst`.
+    ///
+    /// Only the frames that overlap in both buffers will be converted.
+    ///
+    /// Returns `false` if either `src_ch` or `dst_ch` is out of bounds.
+    #[cfg(feature = "convert")]
+    pub fn convert_channel_into<U: Clone + Copy + Default + Sized, const DST_CHANNELS: usize>(
+        &self,
+        src_ch: usize,
+        dst: &mut ChannelBufferRefMut<U, DST_CHANNELS>,
+        dst_ch: usize,
+    ) -> bool
+    where
+        T: SampleConvert<U>,
+    {
+        self.as_ref().convert_channel_into(src_ch, dst, dst_ch)
+    }
+
+    /// Convert every sample in this buffer into the sample type `U` and write them
+    /// interleaved into `dst`.
+    ///
+    /// The samples are first converted one channel at a time into `scratch` and then
+    /// interleaved, processing the buffer in chunks that fit in `scratch`. This method
+    /// never allocates.
+    ///
+    /// Only the frames that fit in `dst` will be converted. Returns the number of
+    /// frames that were written, which is `0` if `scratch` cannot hold at least one
+    /// frame.
+    #[cfg(feature = "convert")]
+    pub fn convert_interleaved_into<U: Clone + Copy + Default + Sized>(
+        &self,
+        dst: &mut [U],
+        scratch: &mut ScratchBuffer<U>,
+    ) -> usize
+    where
+        T: SampleConvert<U>,
+    {
+        self.as_ref().convert_interleaved_into(dst, scratch)
+    }
+
+    /// Convert every sample in this buffer into the sample type `U` and write them
+    /// interleaved into `dst` in a single pass, without an intermediate buffer.
+    ///
+    /// This is the operation an output callback typically needs, such as processing in
+    /// planar `f32` and handing the device interleaved `i16`.
+    ///
+    /// Only the frames that fit in `dst` will be converted. Returns the number of
+    /// frames that were written.
+    #[cfg(feature = "convert")]
+    pub fn interleave_convert_into<U: Clone + Copy + Default + Sized>(&self, dst: &mut [U]) -> usize
+    where
+        T: SampleConvert<U>,
+    {
+        self.as_ref().interleave_convert_into(dst)
+    }
+
+    /// Convert the contents of this buffer and write them interleaved into the buffer
+    /// of a cpal output stream.
+    ///
+    /// `data` is the interleaved buffer handed to the output stream's data callback,
+    /// which must have been opened with `CHANNELS` channels. Only the frames that fit in
+    /// `data` will be written, and any remaining samples in `data` are set to silence.
+    /// Returns the number of frames that were written.
+    #[cfg(feature = "cpal")]
+    pub fn write_to_cpal_output<S>(&self, data: &mut [S]) -> usize
+    where
+        S: Sample + FromSample<T>,
+    {
+        self.as_ref().write_to_cpal_output(data)
+    }
+
+    /// Convert the interleaved samples from the buffer of a cpal input stream and write
+    /// them into this buffer.
+    ///
+    /// `data` is the interleaved buffer handed to the input stream's data callback,
+    /// which must have been opened with `CHANNELS` channels. Only the frames that fit in
+    /// this buffer will be read, and any trailing partial frame in `data` is ignored.
+    /// Returns the number of frames that were written into this buffer.
+    #[cfg(feature = "cpal")]
+    pub fn read_from_cpal_input<S>(&mut self, data: &[S]) -> usize
+    where
+        S: Sample,
+        T: FromSample<S>,
+    {
+        self.as_mut().read_from_cpal_input(data)
+    }
+
+    /// Allocate a new `Vec` containing every sample in this buffer converted to a
+    /// normalized `f32` and interleaved.
+    ///
+    /// This is mostly useful for debugging, such as writing the buffer to a WAV file or
+    /// plotting it.
+    #[cfg(feature = "convert")]
+    pub fn to_interleaved_f32(&self) -> Vec<f32>
+    where
+        T: SampleConvert<f32>,
+    {
+        self.as_ref().to_interleaved_f32()
+    }
+
+    /// Get an iterator over mutable views into each frame in this buffer.
+    pub fn frames_mut(&mut self) -> FramesMut<'_, T, CHANNELS> {
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `frames * CHANNELS`, and none of the channels overlap.
+        // * The Vec is pinned and cannot be moved, so the pointers are valid for the lifetime
+        // of the struct.
+        // * `self` is borrowed as mutable for the lifetime of the iterator.
+        unsafe { FramesMut::new(self.offsets, self.frames) }
+    }
+
+    /// Get a parallel iterator over disjoint blocks of `block` frames in this buffer,
+    /// with each block holding a mutable slice into every channel.
+    ///
+    /// This is useful for effects which are independent across blocks of time, so that
+    /// each block can be processed on a different thread. The last block will be
+    /// shorter if `self.frames()` is not a multiple of `block`.
+    ///
+    /// # Panics
+    /// Panics if `block` is `0`.
+    #[cfg(feature = "rayon")]
+    pub fn par_frame_blocks_mut(
+        &mut self,
+        block: usize,
+    ) -> impl IndexedParallelIterator<Item = [&mut [T]; CHANNELS]>
+    where
+        T: Send,
+    {
+        assert!(block > 0);
+
+        let num_blocks = self.frames.div_ceil(block);
+        let mut chunks = self.as_mut_slices().map(|ch| ch.chunks_mut(block));
+
+        // Every channel has the same number of frames, so each channel yields exactly
+        // `num_blocks` chunks.
+        let blocks: Vec<[&mut [T]; CHANNELS]> = (0..num_blocks)
+            .map(|_| core::array::from_fn(|ch_i| chunks[ch_i].next().unwrap()))
+            .collect();
+
+        blocks.into_par_iter()
+    }
+
+    /// Split this buffer at `frame` into two disjoint mutable views, the first holding
+    /// frames `[0, frame)` and the second holding frames `[frame, frames)` of every
+    /// channel.
+    ///
+    /// The frames of each half are not stored one after the other in a single slice, so
+    /// the halves have no [`raw`](ChannelBufferRefMut::raw) data (see
+    /// [`ChannelBufferRefMut::is_contiguous`]). Every per-channel method works as usual.
+    ///
+    /// Returns `None` if `frame > self.frames()`.
+    pub fn split_frames_mut(
+        &mut self,
+        frame: usize,
+    ) -> Option<(
+        ChannelBufferRefMut<'_, T, CHANNELS>,
+        ChannelBufferRefMut<'_, T, CHANNELS>,
+    )> {
+        if frame > self.frames {
+            return None;
+        }
+
+        // SAFETY:
+        // * The constructors ensure that every pointer points to a region of `frames`
+        // samples in the data vec, and we have checked that `frame <= frames`.
+        // * The Vec is pinned and cannot be moved, so the pointers are valid for the
+        // lifetime of the struct.
+        // * `self` is borrowed as mutable, and the two halves cover disjoint frames of
+        // every channel. Neither half holds a slice of the data vec, so all mutability
+        // rules are being upheld.
+        unsafe {
+            Some((
+                ChannelBufferRefMut::from_raw_channels(self.offsets, frame),
+                ChannelBufferRefMut::from_raw_channels(
+                    self.offsets.map(|ptr| ptr.add(frame)),
+                    self.frames - frame,
+                ),
+            ))
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Get the entire contents of the buffer as a single immutable slice.
+    pub fn raw(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Compute a checksum of the bytes of each channel, such as for logging at each
+    /// stage of a processing chain to find where the data diverges.
+    ///
+    /// This uses the 64-bit FNV-1a hash, which is fast but not cryptographically
+    /// secure, so it must not be used for anything security-related.
+    #[cfg(feature = "bytemuck")]
+    pub fn channel_checksum(&self) -> [u64; CHANNELS]
+    where
+        T: Pod,
+    {
+        self.as_ref().channel_checksum()
+    }
+
+    /// Compute a checksum of the bytes of every channel in this buffer, in channel
+    /// order.
+    ///
+    /// Only the samples in each channel are included, not any unused samples in the
+    /// backing data. See [`channel_checksum`](Self::channel_checksum).
+    #[cfg(feature = "bytemuck")]
+    pub fn checksum(&self) -> u64
+    where
+        T: Pod,
+    {
+        self.as_ref().checksum()
+    }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = CHANNELS * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+
+    /// Get the entire contents of the buffer as a single mutable slice.
+    pub fn raw_mut(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = CHANNELS * self.frames;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        let dst = self.as_bytes_mut();
+        let len = dst.len().min(bytes.len());
+        dst[..len].copy_from_slice(&bytes[..len]);
+        len
+    }
 
     /// Clear all data with the default value.
     pub fn clear(&mut self) {
@@ -335,6 +1410,519 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
         }
     }
 
+    /// Clear all data in the channel at `index` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel(&mut self, index: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        ch.fill(T::default());
+        true
+    }
+
+    /// Clear all data in the channel at `index` up to `frames` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel_frames(&mut self, index: usize, frames: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        let frames = frames.min(ch.len());
+        ch[..frames].fill(T::default());
+        true
+    }
+
+    /// Copy the contents of this buffer into the existing buffer `dst`, such as a
+    /// buffer borrowed from an arena or pool.
+    ///
+    /// This is the same as calling `dst.copy_from(self)`. If the two buffers have a
+    /// different number of frames, then only the frames that overlap in both buffers
+    /// will be copied, and any remaining frames in `dst` are left untouched.
+    pub fn copy_into_buffer(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>) {
+        dst.copy_from(self);
+    }
+
+    /// Copy the contents of `src` into this buffer.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`], such as
+    /// a [`ChannelBufferRef`], a [`ChannelBufferRefMut`], or another [`ChannelBuffer`].
+    ///
+    /// Only the frames that overlap in both buffers will be copied.
+    pub fn copy_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>) {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            dst_ch.copy_from_slice(src_ch);
+        }
+    }
+
+    /// Copy the contents of `src` into this buffer.
+    ///
+    /// This is a strict version of `copy_from()` which returns an error instead of
+    /// copying only the overlapping frames if the buffers have different shapes.
+    pub fn try_copy_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch> {
+        let src = src.as_channel_buffer_ref();
+        self.shape().check_same(src.shape())?;
+        self.copy_from(src);
+        Ok(())
+    }
+
+    /// Copy the contents of `src` into this buffer, starting at the frame
+    /// `dst_start_frame` of this buffer.
+    ///
+    /// This is useful for placing a signal at a sample-accurate position, such as when
+    /// scheduling a one-shot.
+    ///
+    /// Only the frames of `src` that fit between `dst_start_frame` and the end of this
+    /// buffer will be copied.
+    pub fn copy_from_at(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+        dst_start_frame: usize,
+    ) {
+        self.as_mut().copy_from_at(src, dst_start_frame)
+    }
+
+    /// Add the contents of `src` to the contents of this buffer.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn add_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>)
+    where
+        T: AddAssign,
+    {
+        self.as_mut().add_from(src)
+    }
+
+    /// Add the contents of `src` to the contents of this buffer.
+    ///
+    /// This is a strict version of `add_from()` which returns an error instead of
+    /// processing only the overlapping frames if the buffers have different shapes.
+    pub fn try_add_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch>
+    where
+        T: AddAssign,
+    {
+        self.as_mut().try_add_from(src)
+    }
+
+    /// Add the contents of `src` to the contents of this buffer, starting at the frame
+    /// `dst_start_frame` of this buffer.
+    ///
+    /// This is useful for mixing a signal in at a sample-accurate position, such as
+    /// when scheduling a one-shot.
+    ///
+    /// Only the frames of `src` that fit between `dst_start_frame` and the end of this
+    /// buffer will be processed.
+    pub fn add_from_at(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>, dst_start_frame: usize)
+    where
+        T: AddAssign,
+    {
+        self.as_mut().add_from_at(src, dst_start_frame)
+    }
+
+    /// Route channels from `src` into this buffer, such that `map[o] = Some(i)` copies
+    /// channel `i` of `src` into channel `o` of this buffer, and `map[o] = None` fills
+    /// channel `o` of this buffer with the default value.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`], and it may
+    /// have a different number of channels than this buffer. The same source channel
+    /// may be routed to more than one output channel.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    ///
+    /// Returns `false` (without modifying this buffer) if any index in `map` is out of
+    /// bounds of `src`.
+    pub fn route_from<const SRC: usize>(
+        &mut self,
+        src: impl AsChannelBufferRef<T, SRC>,
+        map: &[Option<usize>; CHANNELS],
+    ) -> bool {
+        self.as_mut().route_from(src, map)
+    }
+
+    /// Copy `src` into the channel at index `dst_ch` in reverse order.
+    ///
+    /// Only the frames that overlap in both `src` and the channel will be copied, and
+    /// the overlapping part of `src` is what gets reversed. That is, with
+    /// `n = self.frames().min(src.len())`, frame `i` of the channel is set to
+    /// `src[n - 1 - i]`.
+    ///
+    /// Returns `false` if `dst_ch` is out of bounds.
+    pub fn copy_channel_reversed_from(&mut self, dst_ch: usize, src: &[T]) -> bool {
+        let Some(ch) = self.channel_mut(dst_ch) else {
+            return false;
+        };
+
+        copy_reversed(ch, src);
+
+        true
+    }
+
+    /// Copy the contents of `src` into this buffer with every channel in reverse order.
+    ///
+    /// Only the frames that overlap in both buffers will be copied. See
+    /// [`Self::copy_channel_reversed_from`] for details.
+    pub fn copy_reversed_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>) {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            copy_reversed(dst_ch, src_ch);
+        }
+    }
+
+    /// Multiply every sample in this buffer by the corresponding sample in `src`.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn mul_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>)
+    where
+        T: MulAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d *= s;
+            }
+        }
+    }
+
+    /// Multiply every sample in this buffer by the corresponding sample in `src`.
+    ///
+    /// This is a strict version of `mul_from()` which returns an error instead of
+    /// processing only the overlapping frames if the buffers have different shapes.
+    pub fn try_mul_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch>
+    where
+        T: MulAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        self.shape().check_same(src.shape())?;
+        self.mul_from(src);
+        Ok(())
+    }
+
+    /// Multiply every channel in this buffer by the single channel of `mono`, such as
+    /// when applying an envelope.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn mul_from_mono(&mut self, mono: impl AsChannelBufferRef<T, 1>)
+    where
+        T: MulAssign,
+    {
+        let mono = mono.as_channel_buffer_ref();
+        let frames = self.frames.min(mono.frames());
+        let [src_ch] = mono.as_slices_with_length(frames);
+
+        for dst_ch in self.as_mut_slices_with_length(frames) {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d *= s;
+            }
+        }
+    }
+
+    /// Multiply frame `i` of every channel in this buffer by `env[i]`, such as when
+    /// applying an ADSR or LFO envelope computed at one value per frame.
+    ///
+    /// Only the first `frames().min(env.len())` frames will be processed.
+    pub fn apply_gain_envelope(&mut self, env: &[T])
+    where
+        T: MulAssign,
+    {
+        let frames = self.frames.min(env.len());
+        let mut channels = self.as_mut_slices_with_length(frames);
+
+        for (frame_i, &gain) in env[..frames].iter().enumerate() {
+            for ch in channels.iter_mut() {
+                ch[frame_i] *= gain;
+            }
+        }
+    }
+
+    /// Copy the contents of `src` into this buffer, multiplying every sample by `gain`.
+    ///
+    /// This overwrites the contents of this buffer rather than adding to them.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be copied.
+    #[cfg(feature = "float")]
+    pub fn copy_from_with_gain(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>, gain: T)
+    where
+        T: Float,
+    {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d = s * gain;
+            }
+        }
+    }
+
+    /// Copy the single channel of `mono` into every channel of this buffer, multiplying
+    /// every sample by `gain`.
+    ///
+    /// This overwrites the contents of this buffer rather than adding to them.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    #[cfg(feature = "float")]
+    pub fn upmix_from_mono(&mut self, mono: impl AsChannelBufferRef<T, 1>, gain: T)
+    where
+        T: Float,
+    {
+        let mono = mono.as_channel_buffer_ref();
+        let frames = self.frames.min(mono.frames());
+        let [src_ch] = mono.as_slices_with_length(frames);
+
+        for dst_ch in self.as_mut_slices_with_length(frames) {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d = s * gain;
+            }
+        }
+    }
+
+    /// Move the samples in every channel left by `n` frames, discarding the first `n`
+    /// frames.
+    ///
+    /// If `clear` is `true`, then the last `n` frames will be filled with the default
+    /// value. Otherwise their contents are left unchanged (stale).
+    ///
+    /// Returns the number of frames at the start of the buffer that still contain
+    /// valid (shifted) data.
+    pub fn shift_frames_left(&mut self, n: usize, clear: bool) -> usize {
+        for ch in self.as_mut_slices() {
+            shift_slice_left(ch, n, clear);
+        }
+
+        self.frames - n.min(self.frames)
+    }
+
+    /// Clamp every sample in each channel to the range `[min, max]`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp(&mut self, min: T, max: T)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices() {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Clamp every sample in each channel within the given range of frames to the
+    /// range `[min, max]`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be clamped.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp_range(&mut self, min: T, max: T, range: Range<usize>)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices_with_range(range) {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Apply a linear fade in to the first `frames` frames of every channel, ramping
+    /// from a gain of `0` at the first frame up to `1` at the last faded frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_in(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let frames = frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_in_slice(&mut ch[..frames]);
+        }
+    }
+
+    /// Apply a linear fade out to the last `frames` frames of every channel, ramping
+    /// from a gain of `1` at the first faded frame down to `0` at the last frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_out(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let start = self.frames - frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_out_slice(&mut ch[start..]);
+        }
+    }
+
+    /// Scale every channel by a single gain so that the peak absolute value of any
+    /// sample in this buffer becomes `target_peak`, preserving the balance between
+    /// channels.
+    ///
+    /// Returns the gain that was applied. If every sample is silent, then this does
+    /// nothing and returns a gain of `1`.
+    #[cfg(feature = "float")]
+    pub fn normalize(&mut self, target_peak: T) -> T
+    where
+        T: Float,
+    {
+        let peak = self.peak();
+        if peak == T::ZERO {
+            return T::ONE;
+        }
+
+        let gain = target_peak / peak;
+        for ch in self.as_mut_slices() {
+            apply_gain_slice(ch, gain);
+        }
+
+        gain
+    }
+
+    /// Add triangular-PDF (TPDF) dither noise to every sample in every channel, as the
+    /// last step before reducing the bit depth (e.g. with the `convert_into` family).
+    ///
+    /// `rng` must return uniformly distributed values in the range `[0, 1)`. It is
+    /// called twice per sample, and the difference of the two values scaled by
+    /// `amplitude` is added to the sample, giving noise in the range
+    /// `(-amplitude, amplitude)`.
+    ///
+    /// `amplitude` is usually the size of one least significant bit of the target
+    /// format, such as `1.0 / 32768.0` for 16-bit audio, which fully decorrelates the
+    /// quantization error from the signal.
+    #[cfg(feature = "float")]
+    pub fn apply_tpdf_dither(&mut self, amplitude: T, mut rng: impl FnMut() -> T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            tpdf_dither_slice(ch, amplitude, &mut rng);
+        }
+    }
+
+    /// Apply `tanh(x * drive) / tanh(drive)` soft-clipping saturation to every sample
+    /// in every channel.
+    ///
+    /// This is a smoother, more musical alternative to [`clamp`](Self::clamp). The
+    /// output is normalized so that a sample of `±1` stays at `±1`, and higher values
+    /// of `drive` give a harder knee. This does nothing if `drive <= 0`.
+    ///
+    /// See [`apply_soft_clip_fast`](Self::apply_soft_clip_fast) for a cheaper
+    /// approximation.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_slice(ch, drive);
+        }
+    }
+
+    /// A cheaper version of [`apply_soft_clip`](Self::apply_soft_clip) which uses a
+    /// rational approximation of `tanh` instead of calling `tanh` for every sample.
+    ///
+    /// The approximation differs from `tanh` by less than `0.025` and hard-limits at
+    /// `±3` before normalization, which makes it well suited for real-time use where the
+    /// exact curve does not matter. This does nothing if `drive <= 0`.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip_fast(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_fast_slice(ch, drive);
+        }
+    }
+
+    /// Replace every subnormal (denormal) sample in each channel with zero.
+    ///
+    /// Subnormal values can severely degrade performance on many CPUs, especially
+    /// in feedback loops such as filters and reverbs.
+    #[cfg(feature = "float")]
+    pub fn flush_denormals(&mut self)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            flush_denormals_slice(ch);
+        }
+    }
+
+    /// Allocate a new mono [`ChannelBuffer`] with the same number of frames as this
+    /// buffer, filled with the average of every channel in this buffer.
+    #[cfg(feature = "float")]
+    pub fn to_mono(&self) -> ChannelBuffer<T, 1>
+    where
+        T: Float,
+    {
+        self.as_ref().to_mono()
+    }
+
+    /// Allocate a new [`ChannelBuffer`] holding a copy of the given range of frames in
+    /// every channel of this buffer.
+    ///
+    /// This is useful for baking a processed sub-region of a buffer into a tight new
+    /// allocation, such as when exporting it.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be copied.
+    pub fn trim_range(&self, range: Range<usize>) -> ChannelBuffer<T, CHANNELS> {
+        self.as_ref().trim_range(range)
+    }
+
+    /// Allocate a new [`ChannelBuffer`] holding a copy of the given range of frames in
+    /// every channel of this buffer.
+    ///
+    /// Unlike [`ChannelBuffer::trim_range`], this returns an error if the range is
+    /// invalid or if any part of it falls out of bounds.
+    pub fn try_trim_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<ChannelBuffer<T, CHANNELS>, RangeError> {
+        self.as_ref().try_trim_range(range)
+    }
+
     #[inline(always)]
     pub fn as_ref<'a>(&'a self) -> ChannelBufferRef<'a, T, CHANNELS> {
         // SAFETY:
@@ -356,6 +1944,67 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> ChannelBu
     }
 }
 
+impl<T: Clone + Copy + Default + Sized + Unpin> ChannelBuffer<T, 1> {
+    /// Get the single channel of this mono buffer as an immutable slice. The slice will
+    /// have a length of `self.frames()`.
+    #[inline(always)]
+    pub fn as_mono(&self) -> &[T] {
+        let [ch] = self.as_slices();
+        ch
+    }
+
+    /// Get the single channel of this mono buffer as a mutable slice. The slice will
+    /// have a length of `self.frames()`.
+    #[inline(always)]
+    pub fn as_mono_mut(&mut self) -> &mut [T] {
+        let [ch] = self.as_mut_slices();
+        ch
+    }
+}
+
+impl<T: Clone + Copy + Default + Sized + Unpin> ChannelBuffer<T, 2> {
+    /// Iterate over the `(left, right)` sample pairs of this stereo buffer, one pair
+    /// per frame.
+    #[inline]
+    pub fn stereo_frames(&self) -> impl ExactSizeIterator<Item = (T, T)> + '_ {
+        let [left, right] = self.as_slices();
+        left.iter().copied().zip(right.iter().copied())
+    }
+
+    /// Iterate over mutable references to the `(left, right)` sample pairs of this
+    /// stereo buffer, one pair per frame.
+    #[inline]
+    pub fn stereo_frames_mut(&mut self) -> impl ExactSizeIterator<Item = (&mut T, &mut T)> + '_ {
+        let [left, right] = self.as_mut_slices();
+        left.iter_mut().zip(right.iter_mut())
+    }
+}
+
+#[cfg(feature = "float")]
+impl ChannelBuffer<f32, 2> {
+    /// Apply an equal-power pan to this stereo buffer, where `position` ranges from
+    /// `-1.0` (hard left) to `1.0` (hard right).
+    ///
+    /// The left channel is scaled by `cos(θ)` and the right channel by `sin(θ)`, where
+    /// `θ` maps `position` onto `[0, π/2]`. `law` sets the gain at the center; with
+    /// [`PanLaw::ZeroDb`], a `position` of `0.0` leaves the levels unchanged.
+    ///
+    /// `position` is clamped to the range `[-1.0, 1.0]`.
+    pub fn pan(&mut self, position: f32, law: PanLaw) {
+        self.as_mut().pan(position, law)
+    }
+
+    /// Adjust the stereo width of this buffer by converting each frame to mid/side,
+    /// scaling the side component by `width`, and converting back to left/right.
+    ///
+    /// A `width` of `0.0` collapses the buffer to mono (both channels are set to the
+    /// average of the two), `1.0` leaves it unchanged, and values greater than `1.0`
+    /// widen it. A negative `width` also swaps the left and right sides.
+    pub fn apply_stereo_width(&mut self, width: f32) {
+        self.as_mut().apply_stereo_width(width)
+    }
+}
+
 impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Index<usize>
     for ChannelBuffer<T, CHANNELS>
 {
@@ -376,6 +2025,42 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> IndexMut<
     }
 }
 
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Index<(usize, usize)>
+    for ChannelBuffer<T, CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> IndexMut<(usize, usize)>
+    for ChannelBuffer<T, CHANNELS>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (ch_i, frame_i): (usize, usize)) -> &mut Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // sample can exist.
+        unsafe { &mut *self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Default
     for ChannelBuffer<T, CHANNELS>
 {
@@ -384,6 +2069,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Default
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize>
     Into<ChannelBufferRef<'a, T, CHANNELS>> for &'a ChannelBuffer<T, CHANNELS>
 {
@@ -393,6 +2079,7 @@ impl<'a, T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize>
     Into<ChannelBufferRefMut<'a, T, CHANNELS>> for &'a mut ChannelBuffer<T, CHANNELS>
 {
@@ -402,6 +2089,7 @@ impl<'a, T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Into<Vec<T>>
     for ChannelBuffer<T, CHANNELS>
 {
@@ -410,6 +2098,17 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Into<Vec<
     }
 }
 
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> TryFrom<Vec<T>>
+    for ChannelBuffer<T, CHANNELS>
+{
+    type Error = LayoutError;
+
+    /// See [`ChannelBuffer::try_from_vec`].
+    fn try_from(data: Vec<T>) -> Result<Self, Self::Error> {
+        Self::try_from_vec(data)
+    }
+}
+
 impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Clone
     for ChannelBuffer<T, CHANNELS>
 {
@@ -425,13 +2124,161 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Clone
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `Vec<T>`, so it requires `T: Send`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Send
     for ChannelBuffer<T, CHANNELS>
+where
+    T: Send,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `Vec<T>`, so it requires `T: Sync`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Sync
     for ChannelBuffer<T, CHANNELS>
+where
+    T: Sync,
 {
 }
+
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize>
+    AsChannelBufferRef<T, CHANNELS> for ChannelBuffer<T, CHANNELS>
+{
+    #[inline(always)]
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS> {
+        self.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_raw_parts_with_slack_data() {
+        let buf = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6, 7], 2);
+
+        assert_eq!(buf.raw(), &[1, 2, 3, 4]);
+
+        let cloned = buf.clone();
+        assert_eq!(cloned.frames(), 2);
+        assert_eq!(cloned.raw(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn leak_then_reclaim_round_trip() {
+        let mut data = Vec::with_capacity(8);
+        data.extend([1, 2, 3, 4]);
+        let buf = ChannelBuffer::<u32, 2>::from_raw_parts(data, 2);
+
+        let mut leaked = buf.leak();
+        assert_eq!(leaked.as_slices(), [&[1, 2][..], &[3, 4][..]]);
+        leaked.channel_mut(1).unwrap().fill(9);
+
+        // SAFETY: `leaked` is the value returned by `leak` and has not been reclaimed.
+        let buf = unsafe { ChannelBuffer::reclaim(leaked) };
+        assert_eq!(buf.frames(), 2);
+        assert_eq!(buf.as_slices(), [&[1, 2][..], &[9, 9][..]]);
+
+        let (data, frames) = buf.into_raw_parts();
+        assert_eq!((data.len(), data.capacity(), frames), (4, 4, 2));
+    }
+
+    #[test]
+    fn resize_frames_with_anchor() {
+        let mut buf = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], 3);
+
+        buf.resize_frames_with(2, Anchor::End);
+        assert_eq!(buf.as_slices(), [&[2, 3][..], &[5, 6][..]]);
+
+        buf.resize_frames_with(4, Anchor::End);
+        assert_eq!(buf.as_slices(), [&[0, 0, 2, 3][..], &[0, 0, 5, 6][..]]);
+
+        buf.resize_frames_with(3, Anchor::Start);
+        assert_eq!(buf.as_slices(), [&[0, 0, 2][..], &[0, 0, 5][..]]);
+
+        buf.resize_frames_with(4, Anchor::Start);
+        assert_eq!(buf.as_slices(), [&[0, 0, 2, 0][..], &[0, 0, 5, 0][..]]);
+        assert_eq!(buf.raw().len(), 8);
+    }
+
+    #[test]
+    fn set_frames_within_capacity_repacks_channels() {
+        let mut data = Vec::with_capacity(8);
+        data.extend([1, 2, 3, 4]);
+        let mut buf = ChannelBuffer::<u32, 2>::from_raw_parts(data, 2);
+        let ptr = buf.raw().as_ptr();
+
+        assert!(buf.set_frames_within_capacity(1).is_ok());
+        assert_eq!(buf.as_slices(), [&[1][..], &[3][..]]);
+
+        assert!(buf.set_frames_within_capacity(4).is_ok());
+        assert_eq!(buf.as_slices(), [&[1, 0, 0, 0][..], &[3, 0, 0, 0][..]]);
+        assert_eq!(buf.raw().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn set_frames_within_capacity_rejects_exceeding_capacity() {
+        let mut data = Vec::with_capacity(8);
+        data.extend([1, 2, 3, 4]);
+        let mut buf = ChannelBuffer::<u32, 2>::from_raw_parts(data, 2);
+
+        assert_eq!(
+            buf.set_frames_within_capacity(5),
+            Err(CapacityError {
+                required: 10,
+                capacity: 8
+            })
+        );
+        assert_eq!(
+            buf.set_frames_within_capacity(usize::MAX),
+            Err(CapacityError {
+                required: usize::MAX,
+                capacity: 8
+            })
+        );
+        assert_eq!(buf.as_slices(), [&[1, 2][..], &[3, 4][..]]);
+    }
+
+    #[test]
+    fn split_frames_mut_halves_are_disjoint() {
+        let mut buf = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], 3);
+
+        let (mut a, mut b) = buf.split_frames_mut(1).unwrap();
+        assert_eq!((a.frames(), b.frames()), (1, 2));
+        assert!(!a.is_contiguous() && !b.is_contiguous());
+
+        a.clear();
+        assert_eq!(b.as_slices(), [&[2, 3][..], &[5, 6][..]]);
+        b.channel_mut(1).unwrap().fill(9);
+
+        assert_eq!(buf.raw(), &[0, 2, 3, 0, 9, 9]);
+        assert!(buf.split_frames_mut(4).is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_frames_mut_halves_have_no_raw_data() {
+        let mut buf = ChannelBuffer::<u32, 2>::new(4);
+        let (a, _) = buf.split_frames_mut(2).unwrap();
+        let _ = a.raw();
+    }
+
+    #[test]
+    fn try_add_from_rejects_shape_mismatch() {
+        let mut buf = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![1, 2, 3, 4], 2);
+        let short = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![10, 20], 1);
+
+        assert!(buf.try_add_from(&short).is_err());
+        assert_eq!(buf.raw(), &[1, 2, 3, 4]);
+
+        buf.add_from(&short);
+        assert_eq!(buf.raw(), &[11, 2, 23, 4]);
+
+        let same = ChannelBuffer::<u32, 2>::from_raw_parts(alloc::vec![1, 1, 1, 1], 2);
+        assert!(buf.try_add_from(&same).is_ok());
+        assert_eq!(buf.raw(), &[12, 3, 24, 5]);
+    }
+}
@@ -1,4 +1,42 @@
-use core::ops::{Index, IndexMut, Range};
+#[cfg(all(feature = "alloc", feature = "convert"))]
+use alloc::vec::Vec;
+use core::ops::{AddAssign, Index, IndexMut, MulAssign, Range};
+
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+#[cfg(feature = "cpal")]
+use dasp_sample::{FromSample, Sample};
+
+#[cfg(feature = "convert")]
+use crate::convert::{convert_into_clamped, SampleConvert};
+#[cfg(all(feature = "alloc", feature = "float"))]
+use crate::dsp::average_channels_into;
+#[cfg(feature = "float")]
+use crate::dsp::{
+    apply_gain_slice, fade_in_slice, fade_out_slice, flush_denormals_slice, pan_gains, peak_slice,
+    resample_linear_slice, soft_clip_fast_slice, soft_clip_slice, stereo_width_slices,
+    tpdf_dither_slice, Float, PanLaw,
+};
+#[cfg(feature = "float")]
+use crate::meter::rms_slice;
+use crate::meter::{sum_of_squares_slice, SumOfSquares};
+#[cfg(feature = "prefetch")]
+use crate::prefetch::prefetch_read;
+use crate::util::{
+    check_range, clamp_slice, clamped_frames, copy_reversed, deinterleave, first_frame_where,
+    interleave, last_frame_where, min_max_slice, shift_slice_left,
+};
+#[cfg(feature = "bytemuck")]
+use crate::util::{fnv1a_64, FNV_OFFSET_BASIS};
+#[cfg(feature = "alloc")]
+use crate::ChannelBuffer;
+#[cfg(feature = "alloc")]
+use crate::ScratchBuffer;
+#[cfg(feature = "alloc")]
+use crate::TransposedView;
+use crate::{BufferShape, FramesMut, RangeError, ShapeMismatch};
+
+const NOT_CONTIGUOUS: &str = "the channels of this buffer are not stored in a single slice";
 
 /// An immutable memory-efficient buffer of samples with a fixed compile-time number
 /// of channels each with a fixed runtime number of frames (samples in a single channel
@@ -7,7 +45,8 @@ use core::ops::{Index, IndexMut, Range};
 /// This version uses a reference to a slice as its data source.
 #[derive(Debug, Clone, Copy)]
 pub struct ChannelBufferRef<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
-    data: &'a [T],
+    /// `None` if the channels are not stored one after the other in a single slice.
+    data: Option<&'a [T]>,
     offsets: [*const T; CHANNELS],
     frames: usize,
 }
@@ -20,6 +59,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     };
 
     #[inline(always)]
+    #[allow(clippy::let_unit_value)]
     pub(crate) unsafe fn from_raw(
         data: &'a [T],
         offsets: [*const T; CHANNELS],
@@ -28,13 +68,14 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
         Self {
-            data,
+            data: Some(data),
             offsets,
             frames,
         }
     }
 
     /// Create an empty [`ChannelBufferRef`] with no data.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -42,20 +83,21 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         let offsets = core::array::from_fn(|_| data.as_ptr());
 
         Self {
-            data,
+            data: Some(data),
             offsets,
             frames: 0,
         }
     }
 
     /// Create a new [`ChannelBufferRef`] using the given slice as the data.
+    #[allow(clippy::let_unit_value)]
     pub fn new(data: &'a [T]) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
         let frames = data.len() / CHANNELS;
 
         Self {
-            data,
+            data: Some(data),
             // SAFETY:
             // * All of these pointers point to valid memory in the slice.
             // * We have asserted at compile-time that `CHANNELS` is non-zero.
@@ -68,11 +110,24 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     ///
     /// # Safety
     /// The caller must uphold that `data.len() >= frames * CHANNELS`.
+    ///
+    /// This requirement is checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_unchecked(data: &'a [T], frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        debug_assert!(
+            frames
+                .checked_mul(CHANNELS)
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * CHANNELS` ({} * {})",
+            data.len(),
+            frames,
+            CHANNELS
+        );
+
         Self {
-            data,
+            data: Some(data),
             // SAFETY:
             // * All of these pointers point to valid memory in the slice.
             // * We have asserted at compile-time that `CHANNELS` is non-zero.
@@ -87,6 +142,284 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         self.frames
     }
 
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        first_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        last_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        first_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        last_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        let frames = self.frames.min(dst.len());
+        let dst = &mut dst[..frames];
+        dst.fill(T::ZERO);
+
+        for ch in self.as_slices_with_length(frames) {
+            for (d, &s) in dst.iter_mut().zip(ch) {
+                *d = *d + s * s;
+            }
+        }
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Get the `(min, max)` value of the samples in each channel.
+    ///
+    /// This is useful for scaling the view of a waveform display. Each channel is
+    /// scanned in a single pass. If this buffer is empty, then both values are the
+    /// default value.
+    pub fn value_range(&self) -> [(T, T); CHANNELS]
+    where
+        T: PartialOrd,
+    {
+        self.as_slices().map(min_max_slice)
+    }
+
+    /// Get the `(min, max)` value of the samples across all channels.
+    ///
+    /// If this buffer is empty, then both values are the default value.
+    pub fn value_range_all(&self) -> (T, T)
+    where
+        T: PartialOrd,
+    {
+        let ranges = self.value_range();
+        let (mut min, mut max) = ranges[0];
+        for &(ch_min, ch_max) in &ranges[1..] {
+            if ch_min < min {
+                min = ch_min;
+            }
+            if ch_max > max {
+                max = ch_max;
+            }
+        }
+        (min, max)
+    }
+
+    /// Get the largest absolute value of the samples in each channel.
+    ///
+    /// This is the per-channel version of `peak()`. Each value is `0` if this buffer
+    /// is empty.
+    #[cfg(feature = "float")]
+    pub fn abs_max(&self) -> [T; CHANNELS]
+    where
+        T: Float,
+    {
+        self.as_slices().map(peak_slice)
+    }
+
+    /// Get the sum of the squares of every sample in each channel.
+    ///
+    /// See [`SumOfSquares`] for the type the squares are accumulated in for each
+    /// sample type.
+    pub fn sum_of_squares(&self) -> [T::Accumulator; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_slices().map(sum_of_squares_slice)
+    }
+
+    /// Get the root mean square level of each channel.
+    ///
+    /// The squares are accumulated in a wider type (see [`SumOfSquares`]), so this will
+    /// not overflow for loud integer signals. Returns `0` for each channel if this
+    /// buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn rms(&self) -> [f64; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_slices().map(rms_slice)
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / CHANNELS);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * CHANNELS],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
+    /// Resample the contents of this buffer into `dst` by calling `f(src_channel,
+    /// dst_channel)` once for each pair of channels, in channel order.
+    ///
+    /// This lets an external resampler be plugged in without having to write the
+    /// per-channel loop. The slices passed to `f` have lengths of `self.frames()` and
+    /// `dst.frames()` respectively, and `f` is responsible for any state it needs to
+    /// keep between channels.
+    pub fn resample_each_channel_into(
+        &self,
+        dst: &mut ChannelBufferRefMut<T, CHANNELS>,
+        mut f: impl FnMut(&[T], &mut [T]),
+    ) {
+        for (dst_ch, src_ch) in dst.as_mut_slices().into_iter().zip(self.as_slices()) {
+            f(src_ch, dst_ch);
+        }
+    }
+
+    /// Resample the contents of this buffer into `dst` using linear interpolation,
+    /// stretching or squashing `self.frames()` frames to fit exactly into `dst.frames()`
+    /// frames.
+    ///
+    /// Every channel is read from the same fractional position, so the channels stay
+    /// phase-aligned. This is intended for things like UI scrubbing previews and test
+    /// fixtures rather than high-quality sample rate conversion.
+    ///
+    /// If this buffer is empty, then `dst` is filled with zeros.
+    #[cfg(feature = "float")]
+    pub fn resample_linear_into(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>)
+    where
+        T: Float,
+    {
+        for (dst_ch, src_ch) in dst.as_mut_slices().into_iter().zip(self.as_slices()) {
+            resample_linear_slice(dst_ch, src_ch);
+        }
+    }
+
+    /// Mix the channels of this buffer into the channels of `dst` using a coefficient
+    /// matrix, such that `dst[o][f] = Σ_i matrix[o][i] * self[i][f]`.
+    ///
+    /// Each row of `matrix` holds the gains of every input channel for one output
+    /// channel. This is the general primitive behind downmixing, upmixing, and panning
+    /// (e.g. a surround-to-stereo downmix with ITU coefficients).
+    ///
+    /// This overwrites the contents of `dst` rather than adding to them.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    #[cfg(feature = "float")]
+    pub fn apply_mix_matrix_into<const OUT: usize>(
+        &self,
+        dst: &mut ChannelBufferRefMut<T, OUT>,
+        matrix: &[[T; CHANNELS]; OUT],
+    ) where
+        T: Float,
+    {
+        let frames = self.frames.min(dst.frames());
+        let src = self.as_slices_with_length(frames);
+
+        for (dst_ch, row) in dst
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(matrix)
+        {
+            dst_ch.fill(T::ZERO);
+
+            for (src_ch, &gain) in src.iter().zip(row) {
+                for (d, &s) in dst_ch.iter_mut().zip(src_ch.iter()) {
+                    *d = *d + s * gain;
+                }
+            }
+        }
+    }
+
     /// The number of channels in this buffer.
     pub fn channels(&self) -> usize {
         CHANNELS
@@ -106,6 +439,18 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         }
     }
 
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -124,6 +469,21 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> [&[T]; CHANNELS] {
@@ -171,7 +531,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> [&[T]; CHANNELS] {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         // SAFETY:
         //
@@ -191,9 +551,394 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         }
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`ChannelBufferRef::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<[&[T]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
+    /// Allocate a new mono [`ChannelBuffer`] with the same number of frames as this
+    /// buffer, filled with the average of every channel in this buffer.
+    #[cfg(all(feature = "alloc", feature = "float"))]
+    pub fn to_mono(&self) -> ChannelBuffer<T, 1>
+    where
+        T: Float + Unpin,
+    {
+        let mut mono = ChannelBuffer::new(self.frames);
+        average_channels_into(self, &mut mono[0]);
+        mono
+    }
+
+    /// Allocate a new [`ChannelBuffer`] holding a copy of the given range of frames in
+    /// every channel of this buffer.
+    ///
+    /// This is useful for baking a processed sub-region of a buffer into a tight new
+    /// allocation, such as when exporting it.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be copied.
+    #[cfg(feature = "alloc")]
+    pub fn trim_range(&self, range: Range<usize>) -> ChannelBuffer<T, CHANNELS>
+    where
+        T: Unpin,
+    {
+        let src = self.as_slices_with_range(range);
+
+        let mut buf = ChannelBuffer::new(src[0].len());
+        for (dst_ch, src_ch) in buf.as_mut_slices().into_iter().zip(src) {
+            dst_ch.copy_from_slice(src_ch);
+        }
+
+        buf
+    }
+
+    /// Allocate a new [`ChannelBuffer`] holding a copy of the given range of frames in
+    /// every channel of this buffer.
+    ///
+    /// Unlike [`ChannelBufferRef::trim_range`], this returns an error if the range is
+    /// invalid or if any part of it falls out of bounds.
+    #[cfg(feature = "alloc")]
+    pub fn try_trim_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<ChannelBuffer<T, CHANNELS>, RangeError>
+    where
+        T: Unpin,
+    {
+        check_range(&range, self.frames)?;
+        Ok(self.trim_range(range))
+    }
+
+    /// Allocate a new [`ChannelBuffer`] holding a copy of every channel of this
+    /// buffer.
+    #[cfg(feature = "alloc")]
+    pub fn to_channel_buffer(&self) -> ChannelBuffer<T, CHANNELS>
+    where
+        T: Unpin,
+    {
+        let mut buf = ChannelBuffer::new(self.frames);
+        for (dst_ch, src_ch) in buf.as_mut_slices().into_iter().zip(self.as_slices()) {
+            dst_ch.copy_from_slice(src_ch);
+        }
+
+        buf
+    }
+
+    /// Copy the contents of this buffer into the existing buffer `dst`, such as a
+    /// buffer borrowed from an arena or pool.
+    ///
+    /// This is the same as calling `dst.copy_from(self)`. If the two buffers have a
+    /// different number of frames, then only the frames that overlap in both buffers
+    /// will be copied, and any remaining frames in `dst` are left untouched.
+    pub fn copy_into_buffer(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>) {
+        dst.copy_from(self);
+    }
+
+    /// Get an iterator over each frame in this buffer, with the samples from every
+    /// channel gathered into an array.
+    pub fn interleaved_frames(&self) -> impl ExactSizeIterator<Item = [T; CHANNELS]> + 'a {
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * CHANNELS`.
+        // * The data slice is borrowed for `'a` and cannot be moved, so the pointers are
+        // valid for the lifetime `'a`.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        let slices: [&'a [T]; CHANNELS] = unsafe {
+            core::array::from_fn(|ch_i| {
+                core::slice::from_raw_parts(*self.offsets.get_unchecked(ch_i), self.frames)
+            })
+        };
+
+        (0..self.frames).map(move |frame_i| core::array::from_fn(|ch_i| slices[ch_i][frame_i]))
+    }
+
+    /// Interleave the contents of this buffer into `scratch` and return a view which
+    /// gives contiguous access to every frame.
+    ///
+    /// This trades a single transpose pass for fast frame access, which pays off when
+    /// an algorithm makes many frame-major passes over the buffer. For a single pass,
+    /// prefer `interleaved_frames()`.
+    ///
+    /// Only the frames that fit in `scratch` will be transposed. This method never
+    /// allocates.
+    #[cfg(feature = "alloc")]
+    pub fn transpose_into_interleaved_scratch<'s>(
+        &self,
+        scratch: &'s mut ScratchBuffer<T>,
+    ) -> TransposedView<'s, T, CHANNELS> {
+        let frames = self.frames.min(scratch.len() / CHANNELS);
+        let src = self.as_slices_with_length(frames);
+        let dst = &mut scratch.as_mut_slice()[..frames * CHANNELS];
+
+        interleave(&src, dst);
+
+        TransposedView::new(dst)
+    }
+
+    /// Convert the channel at index `src_ch` into the sample type `U` and write it into
+    /// the channel at index `dst_ch` of `dst`.
+    ///
+    /// Only the frames that overlap in both buffers will be converted.
+    ///
+    /// Returns `false` if either `src_ch` or `dst_ch` is out of bounds.
+    #[cfg(feature = "convert")]
+    pub fn convert_channel_into<U: Clone + Copy + Default + Sized, const DST_CHANNELS: usize>(
+        &self,
+        src_ch: usize,
+        dst: &mut ChannelBufferRefMut<U, DST_CHANNELS>,
+        dst_ch: usize,
+    ) -> bool
+    where
+        T: SampleConvert<U>,
+    {
+        let (Some(src), Some(dst)) = (self.channel(src_ch), dst.channel_mut(dst_ch)) else {
+            return false;
+        };
+
+        convert_into_clamped(src, dst);
+
+        true
+    }
+
+    /// Convert every sample in this buffer into the sample type `U` and write them
+    /// interleaved into `dst`.
+    ///
+    /// The samples are first converted one channel at a time into `scratch` and then
+    /// interleaved, processing the buffer in chunks that fit in `scratch`. This method
+    /// never allocates.
+    ///
+    /// Only the frames that fit in `dst` will be converted. Returns the number of
+    /// frames that were written, which is `0` if `scratch` cannot hold at least one
+    /// frame.
+    #[cfg(all(feature = "alloc", feature = "convert"))]
+    pub fn convert_interleaved_into<U: Clone + Copy + Default + Sized>(
+        &self,
+        dst: &mut [U],
+        scratch: &mut ScratchBuffer<U>,
+    ) -> usize
+    where
+        T: SampleConvert<U>,
+    {
+        let chunk_frames = scratch.len() / CHANNELS;
+        if chunk_frames == 0 {
+            return 0;
+        }
+
+        let frames = self.frames.min(dst.len() / CHANNELS);
+        let scratch = scratch.as_mut_slice();
+
+        let mut start = 0;
+        while start < frames {
+            let len = chunk_frames.min(frames - start);
+
+            for (ch_i, src_ch) in self
+                .as_slices_with_range(start..start + len)
+                .into_iter()
+                .enumerate()
+            {
+                convert_into_clamped(src_ch, &mut scratch[ch_i * len..(ch_i + 1) * len]);
+            }
+
+            let dst = &mut dst[start * CHANNELS..(start + len) * CHANNELS];
+            for (frame_i, dst_frame) in dst.chunks_exact_mut(CHANNELS).enumerate() {
+                for (ch_i, d) in dst_frame.iter_mut().enumerate() {
+                    *d = scratch[ch_i * len + frame_i];
+                }
+            }
+
+            start += len;
+        }
+
+        frames
+    }
+
+    /// Convert every sample in this buffer into the sample type `U` and write them
+    /// interleaved into `dst` in a single pass, without an intermediate buffer.
+    ///
+    /// This is the operation an output callback typically needs, such as processing in
+    /// planar `f32` and handing the device interleaved `i16`.
+    ///
+    /// Only the frames that fit in `dst` will be converted. Returns the number of
+    /// frames that were written.
+    #[cfg(feature = "convert")]
+    pub fn interleave_convert_into<U: Clone + Copy + Default + Sized>(&self, dst: &mut [U]) -> usize
+    where
+        T: SampleConvert<U>,
+    {
+        let frames = self.frames.min(dst.len() / CHANNELS);
+        let src = self.as_slices_with_length(frames);
+
+        for (frame_i, dst_frame) in dst[..frames * CHANNELS]
+            .chunks_exact_mut(CHANNELS)
+            .enumerate()
+        {
+            for (d, src_ch) in dst_frame.iter_mut().zip(src.iter()) {
+                *d = src_ch[frame_i].convert();
+            }
+        }
+
+        frames
+    }
+
+    /// Convert the contents of this buffer and write them interleaved into the buffer
+    /// of a cpal output stream.
+    ///
+    /// `data` is the interleaved buffer handed to the output stream's data callback,
+    /// which must have been opened with `CHANNELS` channels. Only the frames that fit in
+    /// `data` will be written, and any remaining samples in `data` are set to silence.
+    /// Returns the number of frames that were written.
+    #[cfg(feature = "cpal")]
+    pub fn write_to_cpal_output<S>(&self, data: &mut [S]) -> usize
+    where
+        S: Sample + FromSample<T>,
+    {
+        let frames = self.frames.min(data.len() / CHANNELS);
+        let src = self.as_slices_with_length(frames);
+        let (written, rest) = data.split_at_mut(frames * CHANNELS);
+
+        for (frame_i, dst_frame) in written.chunks_exact_mut(CHANNELS).enumerate() {
+            for (d, src_ch) in dst_frame.iter_mut().zip(src.iter()) {
+                *d = S::from_sample(src_ch[frame_i]);
+            }
+        }
+        rest.fill(S::EQUILIBRIUM);
+
+        frames
+    }
+
+    /// Allocate a new `Vec` containing every sample in this buffer converted to a
+    /// normalized `f32` and interleaved.
+    ///
+    /// This is mostly useful for debugging, such as writing the buffer to a WAV file or
+    /// plotting it.
+    #[cfg(all(feature = "alloc", feature = "convert"))]
+    pub fn to_interleaved_f32(&self) -> Vec<f32>
+    where
+        T: SampleConvert<f32>,
+    {
+        let mut out = Vec::with_capacity(self.frames * CHANNELS);
+        for frame in self.interleaved_frames() {
+            out.extend(frame.into_iter().map(SampleConvert::convert));
+        }
+        out
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice, such as the halves returned by `ChannelBuffer::split_frames_mut`.
+    /// See [`is_contiguous`](Self::is_contiguous).
     pub fn raw(&self) -> &[T] {
-        self.data
+        self.data.expect(NOT_CONTIGUOUS)
+    }
+
+    /// Returns `true` if the channels of this buffer are stored one after the other in
+    /// a single slice, in which case [`raw`](Self::raw) does not panic.
+    ///
+    /// This is only `false` for views over part of every channel of a buffer, such as
+    /// the halves returned by `ChannelBuffer::split_frames_mut`.
+    pub fn is_contiguous(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Compute a checksum of the bytes of each channel, such as for logging at each
+    /// stage of a processing chain to find where the data diverges.
+    ///
+    /// This uses the 64-bit FNV-1a hash, which is fast but not cryptographically
+    /// secure, so it must not be used for anything security-related.
+    #[cfg(feature = "bytemuck")]
+    pub fn channel_checksum(&self) -> [u64; CHANNELS]
+    where
+        T: Pod,
+    {
+        self.as_slices()
+            .map(|ch| fnv1a_64(FNV_OFFSET_BASIS, bytemuck::cast_slice(ch)))
+    }
+
+    /// Compute a checksum of the bytes of every channel in this buffer, in channel
+    /// order.
+    ///
+    /// Only the samples in each channel are included, not any unused samples in the
+    /// backing data. See [`channel_checksum`](Self::channel_checksum).
+    #[cfg(feature = "bytemuck")]
+    pub fn checksum(&self) -> u64
+    where
+        T: Pod,
+    {
+        self.as_slices()
+            .into_iter()
+            .fold(FNV_OFFSET_BASIS, |hash, ch| {
+                fnv1a_64(hash, bytemuck::cast_slice(ch))
+            })
+    }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice. See [`is_contiguous`](Self::is_contiguous).
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = CHANNELS * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized> ChannelBufferRef<'a, T, 1> {
+    /// Get the single channel of this mono buffer as an immutable slice. The slice will
+    /// have a length of `self.frames()`.
+    #[inline(always)]
+    pub fn as_mono(&self) -> &[T] {
+        let [ch] = self.as_slices();
+        ch
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized> ChannelBufferRef<'a, T, 2> {
+    /// Iterate over the `(left, right)` sample pairs of this stereo buffer, one pair
+    /// per frame.
+    #[inline]
+    pub fn stereo_frames(&self) -> impl ExactSizeIterator<Item = (T, T)> + '_ {
+        let [left, right] = self.as_slices();
+        left.iter().copied().zip(right.iter().copied())
     }
 }
 
@@ -208,6 +953,24 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Index<usize>
     }
 }
 
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Index<(usize, usize)>
+    for ChannelBufferRef<'a, T, CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Default
     for ChannelBufferRef<'a, T, CHANNELS>
 {
@@ -216,24 +979,33 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Default
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Into<&'a [T]>
     for ChannelBufferRefMut<'a, T, CHANNELS>
 {
     fn into(self) -> &'a [T] {
-        self.data
+        self.data.expect(NOT_CONTIGUOUS)
     }
 }
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// This type only gives shared access to the samples, like `&[T]`, so sending it
+// to another thread requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
     for ChannelBufferRef<'a, T, CHANNELS>
+where
+    T: Sync,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&[T]`, so it requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
     for ChannelBufferRef<'a, T, CHANNELS>
+where
+    T: Sync,
 {
 }
 
@@ -244,7 +1016,8 @@ unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
 /// This version uses a reference to a slice as its data source.
 #[derive(Debug)]
 pub struct ChannelBufferRefMut<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
-    data: &'a mut [T],
+    /// `None` if the channels are not stored one after the other in a single slice.
+    data: Option<&'a mut [T]>,
     offsets: [*mut T; CHANNELS],
     frames: usize,
 }
@@ -257,6 +1030,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     };
 
     #[inline(always)]
+    #[allow(clippy::let_unit_value)]
     pub(crate) unsafe fn from_raw(
         data: &'a mut [T],
         offsets: [*mut T; CHANNELS],
@@ -265,13 +1039,34 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
         Self {
-            data,
+            data: Some(data),
+            offsets,
+            frames,
+        }
+    }
+
+    /// Create a buffer whose channels are not stored one after the other in a single
+    /// slice, so it has no [`raw`](Self::raw) data.
+    ///
+    /// # Safety
+    /// Every pointer in `offsets` must point to a region of `frames` samples which is
+    /// valid for reads and writes for the lifetime `'a`, and none of these regions may
+    /// overlap with each other or with any other live reference.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    #[allow(clippy::let_unit_value)]
+    pub(crate) unsafe fn from_raw_channels(offsets: [*mut T; CHANNELS], frames: usize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        Self {
+            data: None,
             offsets,
             frames,
         }
     }
 
     /// Create an empty [`ChannelBufferRefMut`] with no data.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -279,13 +1074,14 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         let offsets = core::array::from_fn(|_| data.as_mut_ptr());
 
         Self {
-            data,
+            data: Some(data),
             offsets,
             frames: 0,
         }
     }
 
     /// Create a new [`ChannelBufferRefMut`] using the given slice as the data.
+    #[allow(clippy::let_unit_value)]
     pub fn new(data: &'a mut [T]) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -297,7 +1093,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         let offsets = unsafe { core::array::from_fn(|ch_i| data.as_mut_ptr().add(ch_i * frames)) };
 
         Self {
-            data,
+            data: Some(data),
             offsets,
             frames,
         }
@@ -307,16 +1103,29 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     ///
     /// # Safety
     /// The caller must uphold that `data.len() >= frames * CHANNELS`.
+    ///
+    /// This requirement is checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_unchecked(data: &'a mut [T], frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        debug_assert!(
+            frames
+                .checked_mul(CHANNELS)
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * CHANNELS` ({} * {})",
+            data.len(),
+            frames,
+            CHANNELS
+        );
+
         // SAFETY:
         // * All of these pointers point to valid memory in the slice.
         // * We have asserted at compile-time that `CHANNELS` is non-zero.
         let offsets = core::array::from_fn(|ch_i| data.as_mut_ptr().add(ch_i * frames));
 
         Self {
-            data,
+            data: Some(data),
             offsets,
             frames,
         }
@@ -328,65 +1137,378 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         self.frames
     }
 
-    /// The number of channels in this buffer.
-    pub fn channels(&self) -> usize {
-        CHANNELS
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
     }
 
-    #[inline(always)]
-    /// Get an immutable reference to the channel at `index`. The slice will have a length
-    /// of `self.frames()`.
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
     ///
-    /// Returns `None` if `index` is out of bounds.
-    pub fn channel(&self, index: usize) -> Option<&[T]> {
-        if index < CHANNELS {
-            // SAFETY: We haved checked that `index` is within bounds.
-            unsafe { Some(self.channel_unchecked(index)) }
-        } else {
-            None
-        }
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        first_frame_where(self.as_slices(), |s| *s != T::default())
     }
 
-    #[inline(always)]
-    /// Get an immutable reference to the channel at `index`. The slice will have a length
-    /// of `self.frames()`.
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
     ///
-    /// # Safety
-    /// `index` must be less than `self.channels()`
-    pub unsafe fn channel_unchecked(&self, index: usize) -> &[T] {
-        // SAFETY:
-        //
-        // * The constructors ensure that the pointed-to data slice has a length of at
-        // least `frames * CHANNELS`.
-        // * The caller upholds that `index` is within bounds.
-        // * The data slice cannot be moved, so the pointers are valid for the lifetime
-        // of the slice.
-        // * We have asserted at compile-time that `CHANNELS` is non-zero.
-        core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        last_frame_where(self.as_slices(), |s| *s != T::default())
     }
 
-    #[inline(always)]
-    /// Get a mutable reference to the channel at `index`. The slice will have a length
-    /// of `self.frames()`.
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
     ///
-    /// Returns `None` if `index` is out of bounds.
-    pub fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
-        if index < CHANNELS {
-            // SAFETY: We haved checked that `index` is within bounds.
-            unsafe { Some(self.channel_unchecked_mut(index)) }
-        } else {
-            None
-        }
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        first_frame_where(self.as_slices(), |s| s.abs() > threshold)
     }
 
-    #[inline(always)]
-    /// Get a mutable reference to the channel at `index`. The slice will have a length
-    /// of `self.frames()`.
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
     ///
-    /// # Safety
-    /// `index` must be less than `self.channels()`
-    pub unsafe fn channel_unchecked_mut(&mut self, index: usize) -> &mut [T] {
-        // SAFETY:
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        last_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        let frames = self.frames.min(dst.len());
+        let dst = &mut dst[..frames];
+        dst.fill(T::ZERO);
+
+        for ch in self.as_slices_with_length(frames) {
+            for (d, &s) in dst.iter_mut().zip(ch) {
+                *d = *d + s * s;
+            }
+        }
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Get the `(min, max)` value of the samples in each channel.
+    ///
+    /// This is useful for scaling the view of a waveform display. Each channel is
+    /// scanned in a single pass. If this buffer is empty, then both values are the
+    /// default value.
+    pub fn value_range(&self) -> [(T, T); CHANNELS]
+    where
+        T: PartialOrd,
+    {
+        self.as_slices().map(min_max_slice)
+    }
+
+    /// Get the `(min, max)` value of the samples across all channels.
+    ///
+    /// If this buffer is empty, then both values are the default value.
+    pub fn value_range_all(&self) -> (T, T)
+    where
+        T: PartialOrd,
+    {
+        let ranges = self.value_range();
+        let (mut min, mut max) = ranges[0];
+        for &(ch_min, ch_max) in &ranges[1..] {
+            if ch_min < min {
+                min = ch_min;
+            }
+            if ch_max > max {
+                max = ch_max;
+            }
+        }
+        (min, max)
+    }
+
+    /// Get the largest absolute value of the samples in each channel.
+    ///
+    /// This is the per-channel version of `peak()`. Each value is `0` if this buffer
+    /// is empty.
+    #[cfg(feature = "float")]
+    pub fn abs_max(&self) -> [T; CHANNELS]
+    where
+        T: Float,
+    {
+        self.as_slices().map(peak_slice)
+    }
+
+    /// Get the sum of the squares of every sample in each channel.
+    ///
+    /// See [`SumOfSquares`] for the type the squares are accumulated in for each
+    /// sample type.
+    pub fn sum_of_squares(&self) -> [T::Accumulator; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_slices().map(sum_of_squares_slice)
+    }
+
+    /// Get the root mean square level of each channel.
+    ///
+    /// The squares are accumulated in a wider type (see [`SumOfSquares`]), so this will
+    /// not overflow for loud integer signals. Returns `0` for each channel if this
+    /// buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn rms(&self) -> [f64; CHANNELS]
+    where
+        T: SumOfSquares,
+    {
+        self.as_slices().map(rms_slice)
+    }
+
+    /// Copy the interleaved samples in `interleaved` into the frames of this buffer
+    /// starting at `dst_start_frame`.
+    ///
+    /// This is useful for reading from an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in this buffer will be copied, and any
+    /// trailing partial frame in `interleaved` will be ignored. Returns the number of
+    /// frames that were copied.
+    pub fn deinterleave_into_at(&mut self, interleaved: &[T], dst_start_frame: usize) -> usize {
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(interleaved.len() / CHANNELS);
+
+        deinterleave(
+            &interleaved[..frames * CHANNELS],
+            &mut self.as_mut_slices_with_range(start..start + frames),
+        );
+
+        frames
+    }
+
+    /// Copy the samples of the single channel of `interleaved` into this buffer,
+    /// treating them as interleaved frames.
+    ///
+    /// This is the same as [`deinterleave_into_at`](Self::deinterleave_into_at) with a
+    /// `dst_start_frame` of `0`, for pipelines which pass interleaved data around in a
+    /// mono buffer. Returns the number of frames that were copied.
+    pub fn deinterleave_from_mono(&mut self, interleaved: impl AsChannelBufferRef<T, 1>) -> usize {
+        let interleaved = interleaved.as_channel_buffer_ref();
+        let [src] = interleaved.as_slices();
+
+        self.deinterleave_into_at(src, 0)
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / CHANNELS);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * CHANNELS],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
+    /// Resample the contents of this buffer into `dst` by calling `f(src_channel,
+    /// dst_channel)` once for each pair of channels, in channel order.
+    ///
+    /// This lets an external resampler be plugged in without having to write the
+    /// per-channel loop. The slices passed to `f` have lengths of `self.frames()` and
+    /// `dst.frames()` respectively, and `f` is responsible for any state it needs to
+    /// keep between channels.
+    pub fn resample_each_channel_into(
+        &self,
+        dst: &mut ChannelBufferRefMut<T, CHANNELS>,
+        mut f: impl FnMut(&[T], &mut [T]),
+    ) {
+        for (dst_ch, src_ch) in dst.as_mut_slices().into_iter().zip(self.as_slices()) {
+            f(src_ch, dst_ch);
+        }
+    }
+
+    /// Resample the contents of this buffer into `dst` using linear interpolation,
+    /// stretching or squashing `self.frames()` frames to fit exactly into `dst.frames()`
+    /// frames.
+    ///
+    /// Every channel is read from the same fractional position, so the channels stay
+    /// phase-aligned. This is intended for things like UI scrubbing previews and test
+    /// fixtures rather than high-quality sample rate conversion.
+    ///
+    /// If this buffer is empty, then `dst` is filled with zeros.
+    #[cfg(feature = "float")]
+    pub fn resample_linear_into(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>)
+    where
+        T: Float,
+    {
+        for (dst_ch, src_ch) in dst.as_mut_slices().into_iter().zip(self.as_slices()) {
+            resample_linear_slice(dst_ch, src_ch);
+        }
+    }
+
+    /// The number of channels in this buffer.
+    pub fn channels(&self) -> usize {
+        CHANNELS
+    }
+
+    #[inline(always)]
+    /// Get an immutable reference to the channel at `index`. The slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel(&self, index: usize) -> Option<&[T]> {
+        if index < CHANNELS {
+            // SAFETY: We haved checked that `index` is within bounds.
+            unsafe { Some(self.channel_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
+    #[inline(always)]
+    /// Get an immutable reference to the channel at `index`. The slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.channels()`
+    pub unsafe fn channel_unchecked(&self, index: usize) -> &[T] {
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * CHANNELS`.
+        // * The caller upholds that `index` is within bounds.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * We have asserted at compile-time that `CHANNELS` is non-zero.
+        core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
+    }
+
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
+    #[inline(always)]
+    /// Get a mutable reference to the channel at `index`. The slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        if index < CHANNELS {
+            // SAFETY: We haved checked that `index` is within bounds.
+            unsafe { Some(self.channel_unchecked_mut(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the given range of frames in the channel at `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range_mut(&mut self, index: usize, range: Range<usize>) -> Option<&mut [T]> {
+        let ch = self.channel_mut(index)?;
+        let range = clamped_frames(range, ch.len());
+        Some(&mut ch[range])
+    }
+
+    #[inline(always)]
+    /// Get a mutable reference to the channel at `index`. The slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.channels()`
+    pub unsafe fn channel_unchecked_mut(&mut self, index: usize) -> &mut [T] {
+        // SAFETY:
         //
         // * The constructors ensure that the pointed-to data slice has a length of at
         // least `frames * CHANNELS`.
@@ -399,6 +1521,54 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         core::slice::from_raw_parts_mut(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get a mutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRefMut`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer_mut(&mut self, index: usize) -> Option<ChannelBufferRefMut<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel_mut(index)?;
+        let ptr = ch.as_mut_ptr();
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRefMut::from_raw(ch, [ptr], frames)) }
+    }
+
+    /// Get mutable references to the channels at each of the given `indices` at once.
+    /// Each slice will have a length of `self.frames()`.
+    ///
+    /// Returns `None` if any index is out of bounds or if any index appears more than
+    /// once.
+    #[inline]
+    pub fn channels_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut [T]; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.offsets.len() || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * We have checked above that every index is within bounds and that no index
+        // appears more than once, so none of the returned slices overlap.
+        // * `self` is borrowed as mutable, so all mutability rules are being upheld.
+        unsafe {
+            Some(core::array::from_fn(|i| {
+                core::slice::from_raw_parts_mut(
+                    *self.offsets.get_unchecked(indices[i]),
+                    self.frames,
+                )
+            }))
+        }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> [&[T]; CHANNELS] {
@@ -490,7 +1660,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> [&[T]; CHANNELS] {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         // SAFETY:
         //
@@ -510,6 +1680,19 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         }
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`ChannelBufferRefMut::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<[&[T]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
     /// Get all channels as immutable slices in the given range.
     ///
     /// If all or part of the range falls out of bounds, then only the part that falls
@@ -517,7 +1700,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     #[inline]
     pub fn as_mut_slices_with_range(&mut self, range: Range<usize>) -> [&mut [T]; CHANNELS] {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         // SAFETY:
         //
@@ -539,19 +1722,275 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
         }
     }
 
+    /// Get all channels as mutable slices in the given range.
+    ///
+    /// Unlike [`ChannelBufferRefMut::as_mut_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_mut_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<[&mut [T]; CHANNELS], RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_mut_slices_with_range(range))
+    }
+
+    /// Get an iterator over each frame in this buffer, with the samples from every
+    /// channel gathered into an array.
+    pub fn interleaved_frames(&self) -> impl ExactSizeIterator<Item = [T; CHANNELS]> + '_ {
+        let slices = self.as_slices();
+
+        (0..self.frames).map(move |frame_i| core::array::from_fn(|ch_i| slices[ch_i][frame_i]))
+    }
+
+    /// Interleave the contents of this buffer into `scratch` and return a view which
+    /// gives contiguous access to every frame.
+    ///
+    /// This trades a single transpose pass for fast frame access, which pays off when
+    /// an algorithm makes many frame-major passes over the buffer. For a single pass,
+    /// prefer `interleaved_frames()`.
+    ///
+    /// Only the frames that fit in `scratch` will be transposed. This method never
+    /// allocates.
+    #[cfg(feature = "alloc")]
+    pub fn transpose_into_interleaved_scratch<'s>(
+        &self,
+        scratch: &'s mut ScratchBuffer<T>,
+    ) -> TransposedView<'s, T, CHANNELS> {
+        let frames = self.frames.min(scratch.len() / CHANNELS);
+        let src = self.as_slices_with_length(frames);
+        let dst = &mut scratch.as_mut_slice()[..frames * CHANNELS];
+
+        interleave(&src, dst);
+
+        TransposedView::new(dst)
+    }
+
+    /// Convert the contents of this buffer and write them interleaved into the buffer
+    /// of a cpal output stream.
+    ///
+    /// `data` is the interleaved buffer handed to the output stream's data callback,
+    /// which must have been opened with `CHANNELS` channels. Only the frames that fit in
+    /// `data` will be written, and any remaining samples in `data` are set to silence.
+    /// Returns the number of frames that were written.
+    #[cfg(feature = "cpal")]
+    pub fn write_to_cpal_output<S>(&self, data: &mut [S]) -> usize
+    where
+        S: Sample + FromSample<T>,
+    {
+        let frames = self.frames.min(data.len() / CHANNELS);
+        let src = self.as_slices_with_length(frames);
+        let (written, rest) = data.split_at_mut(frames * CHANNELS);
+
+        for (frame_i, dst_frame) in written.chunks_exact_mut(CHANNELS).enumerate() {
+            for (d, src_ch) in dst_frame.iter_mut().zip(src.iter()) {
+                *d = S::from_sample(src_ch[frame_i]);
+            }
+        }
+        rest.fill(S::EQUILIBRIUM);
+
+        frames
+    }
+
+    /// Convert the interleaved samples from the buffer of a cpal input stream and write
+    /// them into this buffer.
+    ///
+    /// `data` is the interleaved buffer handed to the input stream's data callback,
+    /// which must have been opened with `CHANNELS` channels. Only the frames that fit in
+    /// this buffer will be read, and any trailing partial frame in `data` is ignored.
+    /// Returns the number of frames that were written into this buffer.
+    #[cfg(feature = "cpal")]
+    pub fn read_from_cpal_input<S>(&mut self, data: &[S]) -> usize
+    where
+        S: Sample,
+        T: FromSample<S>,
+    {
+        let frames = self.frames.min(data.len() / CHANNELS);
+        let mut dst = self.as_mut_slices_with_length(frames);
+
+        for (frame_i, src_frame) in data.chunks_exact(CHANNELS).take(frames).enumerate() {
+            for (dst_ch, &s) in dst.iter_mut().zip(src_frame) {
+                dst_ch[frame_i] = T::from_sample_(s);
+            }
+        }
+
+        frames
+    }
+
+    /// Get an iterator over mutable views into each frame in this buffer.
+    pub fn frames_mut(&mut self) -> FramesMut<'_, T, CHANNELS> {
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * CHANNELS`, and none of the channels overlap.
+        // * `self` is borrowed as mutable for the lifetime of the iterator.
+        unsafe { FramesMut::new(self.offsets, self.frames) }
+    }
+
+    /// Set the samples of every channel at the frame `index` from an array.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn set_frame(&mut self, index: usize, frame: [T; CHANNELS]) -> bool {
+        if index >= self.frames {
+            return false;
+        }
+
+        for (ch, s) in self.as_mut_slices().into_iter().zip(frame) {
+            ch[index] = s;
+        }
+
+        true
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice, such as the halves returned by `ChannelBuffer::split_frames_mut`.
+    /// See [`is_contiguous`](Self::is_contiguous).
     pub fn raw(&self) -> &[T] {
-        self.data
+        self.data.as_deref().expect(NOT_CONTIGUOUS)
+    }
+
+    /// Returns `true` if the channels of this buffer are stored one after the other in
+    /// a single slice, in which case [`raw`](Self::raw) does not panic.
+    ///
+    /// This is only `false` for views over part of every channel of a buffer, such as
+    /// the halves returned by `ChannelBuffer::split_frames_mut`.
+    pub fn is_contiguous(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Compute a checksum of the bytes of each channel, such as for logging at each
+    /// stage of a processing chain to find where the data diverges.
+    ///
+    /// This uses the 64-bit FNV-1a hash, which is fast but not cryptographically
+    /// secure, so it must not be used for anything security-related.
+    #[cfg(feature = "bytemuck")]
+    pub fn channel_checksum(&self) -> [u64; CHANNELS]
+    where
+        T: Pod,
+    {
+        self.as_slices()
+            .map(|ch| fnv1a_64(FNV_OFFSET_BASIS, bytemuck::cast_slice(ch)))
+    }
+
+    /// Compute a checksum of the bytes of every channel in this buffer, in channel
+    /// order.
+    ///
+    /// Only the samples in each channel are included, not any unused samples in the
+    /// backing data. See [`channel_checksum`](Self::channel_checksum).
+    #[cfg(feature = "bytemuck")]
+    pub fn checksum(&self) -> u64
+    where
+        T: Pod,
+    {
+        self.as_slices()
+            .into_iter()
+            .fold(FNV_OFFSET_BASIS, |hash, ch| {
+                fnv1a_64(hash, bytemuck::cast_slice(ch))
+            })
+    }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice. See [`is_contiguous`](Self::is_contiguous).
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = CHANNELS * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
     }
 
     /// Get the entire contents of the buffer as a single mutable slice.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice. See [`is_contiguous`](Self::is_contiguous).
     pub fn raw_mut(&mut self) -> &mut [T] {
-        &mut self.data[..]
+        self.data.as_deref_mut().expect(NOT_CONTIGUOUS)
+    }
+
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    ///
+    /// # Panics
+    /// Panics if the channels of this buffer are not stored one after the other in a
+    /// single slice. See [`is_contiguous`](Self::is_contiguous).
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = CHANNELS * self.frames;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        if self.is_contiguous() {
+            let dst = self.as_bytes_mut();
+            let len = dst.len().min(bytes.len());
+            dst[..len].copy_from_slice(&bytes[..len]);
+            return len;
+        }
+
+        let mut copied = 0;
+        for ch in self.as_mut_slices() {
+            let dst: &mut [u8] = bytemuck::cast_slice_mut(ch);
+            let len = dst.len().min(bytes.len() - copied);
+            dst[..len].copy_from_slice(&bytes[copied..copied + len]);
+            copied += len;
+        }
+        copied
     }
 
     /// Clear all data with the default value.
     pub fn clear(&mut self) {
-        self.raw_mut().fill(T::default());
+        if self.is_contiguous() {
+            self.raw_mut().fill(T::default());
+        } else {
+            for ch in self.as_mut_slices() {
+                ch.fill(T::default());
+            }
+        }
     }
 
     /// Clear all data in each channel up to `frames` with the default value.
@@ -560,6 +1999,621 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
             ch.fill(T::default());
         }
     }
+
+    /// Clear all data in the channel at `index` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel(&mut self, index: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        ch.fill(T::default());
+        true
+    }
+
+    /// Clear all data in the channel at `index` up to `frames` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel_frames(&mut self, index: usize, frames: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        let frames = frames.min(ch.len());
+        ch[..frames].fill(T::default());
+        true
+    }
+
+    /// Copy the contents of this buffer into the existing buffer `dst`, such as a
+    /// buffer borrowed from an arena or pool.
+    ///
+    /// This is the same as calling `dst.copy_from(self)`. If the two buffers have a
+    /// different number of frames, then only the frames that overlap in both buffers
+    /// will be copied, and any remaining frames in `dst` are left untouched.
+    pub fn copy_into_buffer(&self, dst: &mut ChannelBufferRefMut<T, CHANNELS>) {
+        dst.copy_from(self);
+    }
+
+    /// Copy the contents of `src` into this buffer.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`], such as
+    /// a [`ChannelBufferRef`], a [`ChannelBufferRefMut`], or an owned `ChannelBuffer`.
+    ///
+    /// Only the frames that overlap in both buffers will be copied.
+    pub fn copy_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>) {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            dst_ch.copy_from_slice(src_ch);
+        }
+    }
+
+    /// Copy the contents of `src` into this buffer.
+    ///
+    /// This is a strict version of `copy_from()` which returns an error instead of
+    /// copying only the overlapping frames if the buffers have different shapes.
+    pub fn try_copy_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch> {
+        let src = src.as_channel_buffer_ref();
+        self.shape().check_same(src.shape())?;
+        self.copy_from(src);
+        Ok(())
+    }
+
+    /// Copy the contents of `src` into this buffer, starting at the frame
+    /// `dst_start_frame` of this buffer.
+    ///
+    /// This is useful for placing a signal at a sample-accurate position, such as when
+    /// scheduling a one-shot.
+    ///
+    /// Only the frames of `src` that fit between `dst_start_frame` and the end of this
+    /// buffer will be copied.
+    pub fn copy_from_at(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+        dst_start_frame: usize,
+    ) {
+        let src = src.as_channel_buffer_ref();
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_range(start..start + frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            dst_ch.copy_from_slice(src_ch);
+        }
+    }
+
+    /// Add the contents of `src` to the contents of this buffer.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn add_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>)
+    where
+        T: AddAssign,
+    {
+        self.add_from_at(src, 0)
+    }
+
+    /// Add the contents of `src` to the contents of this buffer.
+    ///
+    /// This is a strict version of `add_from()` which returns an error instead of
+    /// processing only the overlapping frames if the buffers have different shapes.
+    pub fn try_add_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch>
+    where
+        T: AddAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        self.shape().check_same(src.shape())?;
+        self.add_from(src);
+        Ok(())
+    }
+
+    /// Add the contents of `src` to the contents of this buffer, starting at the frame
+    /// `dst_start_frame` of this buffer.
+    ///
+    /// This is useful for mixing a signal in at a sample-accurate position, such as
+    /// when scheduling a one-shot.
+    ///
+    /// Only the frames of `src` that fit between `dst_start_frame` and the end of this
+    /// buffer will be processed.
+    pub fn add_from_at(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>, dst_start_frame: usize)
+    where
+        T: AddAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_range(start..start + frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d += s;
+            }
+        }
+    }
+
+    /// Route channels from `src` into this buffer, such that `map[o] = Some(i)` copies
+    /// channel `i` of `src` into channel `o` of this buffer, and `map[o] = None` fills
+    /// channel `o` of this buffer with the default value.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`], and it may
+    /// have a different number of channels than this buffer. The same source channel
+    /// may be routed to more than one output channel.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    ///
+    /// Returns `false` (without modifying this buffer) if any index in `map` is out of
+    /// bounds of `src`.
+    pub fn route_from<const SRC: usize>(
+        &mut self,
+        src: impl AsChannelBufferRef<T, SRC>,
+        map: &[Option<usize>; CHANNELS],
+    ) -> bool {
+        if map.iter().flatten().any(|&i| i >= SRC) {
+            return false;
+        }
+
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+        let src = src.as_slices_with_length(frames);
+
+        for (dst_ch, route) in self.as_mut_slices_with_length(frames).into_iter().zip(map) {
+            match route {
+                Some(i) => dst_ch.copy_from_slice(src[*i]),
+                None => dst_ch.fill(T::default()),
+            }
+        }
+
+        true
+    }
+
+    /// Copy `src` into the channel at index `dst_ch` in reverse order.
+    ///
+    /// Only the frames that overlap in both `src` and the channel will be copied, and
+    /// the overlapping part of `src` is what gets reversed. That is, with
+    /// `n = self.frames().min(src.len())`, frame `i` of the channel is set to
+    /// `src[n - 1 - i]`.
+    ///
+    /// Returns `false` if `dst_ch` is out of bounds.
+    pub fn copy_channel_reversed_from(&mut self, dst_ch: usize, src: &[T]) -> bool {
+        let Some(ch) = self.channel_mut(dst_ch) else {
+            return false;
+        };
+
+        copy_reversed(ch, src);
+
+        true
+    }
+
+    /// Copy the contents of `src` into this buffer with every channel in reverse order.
+    ///
+    /// Only the frames that overlap in both buffers will be copied. See
+    /// [`Self::copy_channel_reversed_from`] for details.
+    pub fn copy_reversed_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>) {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            copy_reversed(dst_ch, src_ch);
+        }
+    }
+
+    /// Multiply every sample in this buffer by the corresponding sample in `src`.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn mul_from(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>)
+    where
+        T: MulAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d *= s;
+            }
+        }
+    }
+
+    /// Multiply every sample in this buffer by the corresponding sample in `src`.
+    ///
+    /// This is a strict version of `mul_from()` which returns an error instead of
+    /// processing only the overlapping frames if the buffers have different shapes.
+    pub fn try_mul_from(
+        &mut self,
+        src: impl AsChannelBufferRef<T, CHANNELS>,
+    ) -> Result<(), ShapeMismatch>
+    where
+        T: MulAssign,
+    {
+        let src = src.as_channel_buffer_ref();
+        self.shape().check_same(src.shape())?;
+        self.mul_from(src);
+        Ok(())
+    }
+
+    /// Multiply every channel in this buffer by the single channel of `mono`, such as
+    /// when applying an envelope.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    pub fn mul_from_mono(&mut self, mono: impl AsChannelBufferRef<T, 1>)
+    where
+        T: MulAssign,
+    {
+        let mono = mono.as_channel_buffer_ref();
+        let frames = self.frames.min(mono.frames());
+        let [src_ch] = mono.as_slices_with_length(frames);
+
+        for dst_ch in self.as_mut_slices_with_length(frames) {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d *= s;
+            }
+        }
+    }
+
+    /// Multiply frame `i` of every channel in this buffer by `env[i]`, such as when
+    /// applying an ADSR or LFO envelope computed at one value per frame.
+    ///
+    /// Only the first `frames().min(env.len())` frames will be processed.
+    pub fn apply_gain_envelope(&mut self, env: &[T])
+    where
+        T: MulAssign,
+    {
+        let frames = self.frames.min(env.len());
+        let mut channels = self.as_mut_slices_with_length(frames);
+
+        for (frame_i, &gain) in env[..frames].iter().enumerate() {
+            for ch in channels.iter_mut() {
+                ch[frame_i] *= gain;
+            }
+        }
+    }
+
+    /// Copy the contents of `src` into this buffer, multiplying every sample by `gain`.
+    ///
+    /// This overwrites the contents of this buffer rather than adding to them.
+    ///
+    /// `src` can be any type that can be borrowed as a [`ChannelBufferRef`].
+    ///
+    /// Only the frames that overlap in both buffers will be copied.
+    #[cfg(feature = "float")]
+    pub fn copy_from_with_gain(&mut self, src: impl AsChannelBufferRef<T, CHANNELS>, gain: T)
+    where
+        T: Float,
+    {
+        let src = src.as_channel_buffer_ref();
+        let frames = self.frames.min(src.frames());
+
+        for (dst_ch, src_ch) in self
+            .as_mut_slices_with_length(frames)
+            .into_iter()
+            .zip(src.as_slices_with_length(frames))
+        {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d = s * gain;
+            }
+        }
+    }
+
+    /// Copy the single channel of `mono` into every channel of this buffer, multiplying
+    /// every sample by `gain`.
+    ///
+    /// This overwrites the contents of this buffer rather than adding to them.
+    ///
+    /// Only the frames that overlap in both buffers will be processed.
+    #[cfg(feature = "float")]
+    pub fn upmix_from_mono(&mut self, mono: impl AsChannelBufferRef<T, 1>, gain: T)
+    where
+        T: Float,
+    {
+        let mono = mono.as_channel_buffer_ref();
+        let frames = self.frames.min(mono.frames());
+        let [src_ch] = mono.as_slices_with_length(frames);
+
+        for dst_ch in self.as_mut_slices_with_length(frames) {
+            for (d, &s) in dst_ch.iter_mut().zip(src_ch) {
+                *d = s * gain;
+            }
+        }
+    }
+
+    /// Move the samples in every channel left by `n` frames, discarding the first `n`
+    /// frames.
+    ///
+    /// If `clear` is `true`, then the last `n` frames will be filled with the default
+    /// value. Otherwise their contents are left unchanged (stale).
+    ///
+    /// Returns the number of frames at the start of the buffer that still contain
+    /// valid (shifted) data.
+    pub fn shift_frames_left(&mut self, n: usize, clear: bool) -> usize {
+        for ch in self.as_mut_slices() {
+            shift_slice_left(ch, n, clear);
+        }
+
+        self.frames - n.min(self.frames)
+    }
+
+    /// Clamp every sample in each channel to the range `[min, max]`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp(&mut self, min: T, max: T)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices() {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Clamp every sample in each channel within the given range of frames to the
+    /// range `[min, max]`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be clamped.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp_range(&mut self, min: T, max: T, range: Range<usize>)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices_with_range(range) {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Apply a linear fade in to the first `frames` frames of every channel, ramping
+    /// from a gain of `0` at the first frame up to `1` at the last faded frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_in(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let frames = frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_in_slice(&mut ch[..frames]);
+        }
+    }
+
+    /// Apply a linear fade out to the last `frames` frames of every channel, ramping
+    /// from a gain of `1` at the first faded frame down to `0` at the last frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_out(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let start = self.frames - frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_out_slice(&mut ch[start..]);
+        }
+    }
+
+    /// Scale every channel by a single gain so that the peak absolute value of any
+    /// sample in this buffer becomes `target_peak`, preserving the balance between
+    /// channels.
+    ///
+    /// Returns the gain that was applied. If every sample is silent, then this does
+    /// nothing and returns a gain of `1`.
+    #[cfg(feature = "float")]
+    pub fn normalize(&mut self, target_peak: T) -> T
+    where
+        T: Float,
+    {
+        let peak = self.peak();
+        if peak == T::ZERO {
+            return T::ONE;
+        }
+
+        let gain = target_peak / peak;
+        for ch in self.as_mut_slices() {
+            apply_gain_slice(ch, gain);
+        }
+
+        gain
+    }
+
+    /// Add triangular-PDF (TPDF) dither noise to every sample in every channel, as the
+    /// last step before reducing the bit depth (e.g. with the `convert_into` family).
+    ///
+    /// `rng` must return uniformly distributed values in the range `[0, 1)`. It is
+    /// called twice per sample, and the difference of the two values scaled by
+    /// `amplitude` is added to the sample, giving noise in the range
+    /// `(-amplitude, amplitude)`.
+    ///
+    /// `amplitude` is usually the size of one least significant bit of the target
+    /// format, such as `1.0 / 32768.0` for 16-bit audio, which fully decorrelates the
+    /// quantization error from the signal.
+    #[cfg(feature = "float")]
+    pub fn apply_tpdf_dither(&mut self, amplitude: T, mut rng: impl FnMut() -> T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            tpdf_dither_slice(ch, amplitude, &mut rng);
+        }
+    }
+
+    /// Apply `tanh(x * drive) / tanh(drive)` soft-clipping saturation to every sample
+    /// in every channel.
+    ///
+    /// This is a smoother, more musical alternative to [`clamp`](Self::clamp). The
+    /// output is normalized so that a sample of `±1` stays at `±1`, and higher values
+    /// of `drive` give a harder knee. This does nothing if `drive <= 0`.
+    ///
+    /// See [`apply_soft_clip_fast`](Self::apply_soft_clip_fast) for a cheaper
+    /// approximation.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_slice(ch, drive);
+        }
+    }
+
+    /// A cheaper version of [`apply_soft_clip`](Self::apply_soft_clip) which uses a
+    /// rational approximation of `tanh` instead of calling `tanh` for every sample.
+    ///
+    /// The approximation differs from `tanh` by less than `0.025` and hard-limits at
+    /// `±3` before normalization, which makes it well suited for real-time use where the
+    /// exact curve does not matter. This does nothing if `drive <= 0`.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip_fast(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_fast_slice(ch, drive);
+        }
+    }
+
+    /// Replace every subnormal (denormal) sample in each channel with zero.
+    ///
+    /// Subnormal values can severely degrade performance on many CPUs, especially
+    /// in feedback loops such as filters and reverbs.
+    #[cfg(feature = "float")]
+    pub fn flush_denormals(&mut self)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            flush_denormals_slice(ch);
+        }
+    }
+
+    /// Overwrite every sample in the buffer with the value returned by `f`.
+    ///
+    /// `f` is called with the arguments `(channel_index, frame_index)`. The previous
+    /// contents of the buffer are ignored.
+    pub fn fill_from_fn(&mut self, mut f: impl FnMut(usize, usize) -> T) {
+        for (ch_i, ch) in self.as_mut_slices().into_iter().enumerate() {
+            for (frame_i, s) in ch.iter_mut().enumerate() {
+                *s = f(ch_i, frame_i);
+            }
+        }
+    }
+
+    /// Overwrite every sample in the channel at `index` with the value returned by `f`.
+    ///
+    /// `f` is called with the frame index as its argument. The previous contents of
+    /// the channel are ignored.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn fill_channel_from_fn(&mut self, index: usize, mut f: impl FnMut(usize) -> T) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        for (frame_i, s) in ch.iter_mut().enumerate() {
+            *s = f(frame_i);
+        }
+
+        true
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized> ChannelBufferRefMut<'a, T, 1> {
+    /// Get the single channel of this mono buffer as an immutable slice. The slice will
+    /// have a length of `self.frames()`.
+    #[inline(always)]
+    pub fn as_mono(&self) -> &[T] {
+        let [ch] = self.as_slices();
+        ch
+    }
+
+    /// Get the single channel of this mono buffer as a mutable slice. The slice will
+    /// have a length of `self.frames()`.
+    #[inline(always)]
+    pub fn as_mono_mut(&mut self) -> &mut [T] {
+        let [ch] = self.as_mut_slices();
+        ch
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized> ChannelBufferRefMut<'a, T, 2> {
+    /// Iterate over the `(left, right)` sample pairs of this stereo buffer, one pair
+    /// per frame.
+    #[inline]
+    pub fn stereo_frames(&self) -> impl ExactSizeIterator<Item = (T, T)> + '_ {
+        let [left, right] = self.as_slices();
+        left.iter().copied().zip(right.iter().copied())
+    }
+
+    /// Iterate over mutable references to the `(left, right)` sample pairs of this
+    /// stereo buffer, one pair per frame.
+    #[inline]
+    pub fn stereo_frames_mut(&mut self) -> impl ExactSizeIterator<Item = (&mut T, &mut T)> + '_ {
+        let [left, right] = self.as_mut_slices();
+        left.iter_mut().zip(right.iter_mut())
+    }
+}
+
+#[cfg(feature = "float")]
+impl<'a> ChannelBufferRefMut<'a, f32, 2> {
+    /// Apply an equal-power pan to this stereo buffer, where `position` ranges from
+    /// `-1.0` (hard left) to `1.0` (hard right).
+    ///
+    /// The left channel is scaled by `cos(θ)` and the right channel by `sin(θ)`, where
+    /// `θ` maps `position` onto `[0, π/2]`. `law` sets the gain at the center; with
+    /// [`PanLaw::ZeroDb`], a `position` of `0.0` leaves the levels unchanged.
+    ///
+    /// `position` is clamped to the range `[-1.0, 1.0]`.
+    pub fn pan(&mut self, position: f32, law: PanLaw) {
+        let (left_gain, right_gain) = pan_gains(position, law);
+        let [left, right] = self.as_mut_slices();
+
+        apply_gain_slice(left, left_gain);
+        apply_gain_slice(right, right_gain);
+    }
+
+    /// Adjust the stereo width of this buffer by converting each frame to mid/side,
+    /// scaling the side component by `width`, and converting back to left/right.
+    ///
+    /// A `width` of `0.0` collapses the buffer to mono (both channels are set to the
+    /// average of the two), `1.0` leaves it unchanged, and values greater than `1.0`
+    /// widen it. A negative `width` also swaps the left and right sides.
+    pub fn apply_stereo_width(&mut self, width: f32) {
+        let [left, right] = self.as_mut_slices();
+        stereo_width_slices(left, right, width);
+    }
 }
 
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Index<usize>
@@ -582,6 +2636,42 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> IndexMut<usiz
     }
 }
 
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Index<(usize, usize)>
+    for ChannelBufferRefMut<'a, T, CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> IndexMut<(usize, usize)>
+    for ChannelBufferRefMut<'a, T, CHANNELS>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (ch_i, frame_i): (usize, usize)) -> &mut Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // sample can exist.
+        unsafe { &mut *self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Default
     for ChannelBufferRefMut<'a, T, CHANNELS>
 {
@@ -590,13 +2680,14 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Default
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     Into<ChannelBufferRef<'a, T, CHANNELS>> for ChannelBufferRefMut<'a, T, CHANNELS>
 {
     #[inline(always)]
     fn into(self) -> ChannelBufferRef<'a, T, CHANNELS> {
         ChannelBufferRef {
-            data: self.data,
+            data: self.data.map(|data| &*data),
             // SAFETY: `[*const T; CHANNELS]` and `[*mut T; CHANNELS]` are interchangeable bit-for-bit.
             offsets: unsafe { core::mem::transmute_copy(&self.offsets) },
             frames: self.frames,
@@ -604,23 +2695,118 @@ impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Into<&'a mut [T]>
     for ChannelBufferRefMut<'a, T, CHANNELS>
 {
     fn into(self) -> &'a mut [T] {
-        self.data
+        self.data.expect(NOT_CONTIGUOUS)
     }
 }
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `&mut [T]`, so it requires `T: Send`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
     for ChannelBufferRefMut<'a, T, CHANNELS>
+where
+    T: Send,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&mut [T]`, so it requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
     for ChannelBufferRefMut<'a, T, CHANNELS>
+where
+    T: Sync,
+{
+}
+
+/// A type that can be borrowed as a [`ChannelBufferRef`].
+pub trait AsChannelBufferRef<T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
+    /// Get an immutable [`ChannelBufferRef`] to this buffer.
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS>;
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> AsChannelBufferRef<T, CHANNELS>
+    for ChannelBufferRef<'a, T, CHANNELS>
 {
+    #[inline(always)]
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS> {
+        *self
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> AsChannelBufferRef<T, CHANNELS>
+    for ChannelBufferRefMut<'a, T, CHANNELS>
+{
+    #[inline(always)]
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS> {
+        ChannelBufferRef {
+            data: self.data.as_deref(),
+            // SAFETY: `[*const T; CHANNELS]` and `[*mut T; CHANNELS]` are interchangeable bit-for-bit.
+            offsets: unsafe { core::mem::transmute_copy(&self.offsets) },
+            frames: self.frames,
+        }
+    }
+}
+
+impl<T: Clone + Copy + Default + Sized, const CHANNELS: usize, B> AsChannelBufferRef<T, CHANNELS>
+    for &B
+where
+    B: AsChannelBufferRef<T, CHANNELS> + ?Sized,
+{
+    #[inline(always)]
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS> {
+        (**self).as_channel_buffer_ref()
+    }
+}
+
+impl<T: Clone + Copy + Default + Sized, const CHANNELS: usize, B> AsChannelBufferRef<T, CHANNELS>
+    for &mut B
+where
+    B: AsChannelBufferRef<T, CHANNELS> + ?Sized,
+{
+    #[inline(always)]
+    fn as_channel_buffer_ref(&self) -> ChannelBufferRef<'_, T, CHANNELS> {
+        (**self).as_channel_buffer_ref()
+    }
+}
+
+#[cfg(all(test, feature = "bytemuck"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip_limited_view() {
+        let src_data = [1u32, 2, 3, 4, 5, 6, 7];
+        // SAFETY: `src_data` holds at least `3 * 2` samples.
+        let src = unsafe { ChannelBufferRef::<u32, 2>::new_unchecked(&src_data, 3) };
+        assert_eq!(src.as_bytes().len(), 6 * 4);
+
+        let mut dst_data = [0u32; 7];
+        // SAFETY: `dst_data` holds at least `3 * 2` samples.
+        let mut dst = unsafe { ChannelBufferRefMut::<u32, 2>::new_unchecked(&mut dst_data, 3) };
+        assert_eq!(dst.copy_from_bytes(src.as_bytes()), 6 * 4);
+        assert_eq!(dst_data, [1, 2, 3, 4, 5, 6, 0]);
+
+        // SAFETY: `dst_data` holds at least `3 * 2` samples.
+        let mut dst = unsafe { ChannelBufferRefMut::<u32, 2>::new_unchecked(&mut dst_data, 3) };
+        assert_eq!(dst.copy_from_bytes(bytemuck::cast_slice(&[9u32; 7])), 6 * 4);
+        assert_eq!(dst_data, [9, 9, 9, 9, 9, 9, 0]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn copy_from_bytes_into_split_half() {
+        let mut buf = ChannelBuffer::<u32, 2>::new(3);
+        let (_, mut b) = buf.split_frames_mut(1).unwrap();
+
+        let bytes: &[u8] = bytemuck::cast_slice(&[1u32, 2, 3]);
+        assert_eq!(b.copy_from_bytes(bytes), 3 * 4);
+        assert_eq!(buf.raw(), &[0, 1, 2, 0, 3, 0]);
+    }
 }
@@ -0,0 +1,195 @@
+/// A sample type which can be converted into the sample type `U`.
+///
+/// Floating-point samples are expected to be in the range `[-1.0, 1.0]`, and integer
+/// samples use their full range.
+///
+/// # Rounding and clipping
+///
+/// * Converting a floating-point sample into an integer sample rounds to the nearest
+///   integer (with halfway cases rounded away from zero). Samples which are out of range
+///   saturate to the integer's minimum or maximum value, and NaN maps to zero. Note that
+///   this means `1.0` maps to the integer's maximum value.
+/// * Converting an integer sample into a narrower integer sample discards the low bits,
+///   truncating towards negative infinity.
+/// * Converting an `f64` sample into an `f32` sample rounds to the nearest
+///   representable value.
+pub trait SampleConvert<U>: Sized {
+    /// Convert this sample into the sample type `U`, saturating any out-of-range
+    /// values.
+    fn convert(self) -> U;
+
+    /// Convert this sample into the sample type `U` without clamping out-of-range
+    /// values.
+    ///
+    /// This can be faster than [`SampleConvert::convert`] when converting from a
+    /// floating-point sample into an integer sample. For all other conversions this is
+    /// identical to [`SampleConvert::convert`].
+    ///
+    /// # Safety
+    /// When converting from a floating-point sample into an integer sample, the sample
+    /// must not be NaN, and the rounded result must be representable in `U`.
+    ///
+    /// Because of rounding, this is a narrower range than `[-1.0, 1.0)`. For an
+    /// integer with `N` bits the sample must be less than `1.0 - 0.5 / 2^(N-1)`. For
+    /// example, `0.99999` rounds to `32768` when converting into an `i16`, which is
+    /// out of range.
+    #[inline(always)]
+    unsafe fn convert_unchecked(self) -> U {
+        self.convert()
+    }
+}
+
+macro_rules! impl_identity {
+    ($($t:ty),*) => {
+        $(
+            impl SampleConvert<$t> for $t {
+                #[inline(always)]
+                fn convert(self) -> $t {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_identity!(f32, f64, i8, i16, i32);
+
+impl SampleConvert<f64> for f32 {
+    #[inline(always)]
+    fn convert(self) -> f64 {
+        self as f64
+    }
+}
+
+impl SampleConvert<f32> for f64 {
+    #[inline(always)]
+    fn convert(self) -> f32 {
+        self as f32
+    }
+}
+
+/// Offset `x` by one half away from zero, so that truncating the result rounds `x`
+/// to the nearest integer.
+#[inline(always)]
+fn round_offset(x: f64) -> f64 {
+    if x < 0.0 {
+        x - 0.5
+    } else {
+        x + 0.5
+    }
+}
+
+macro_rules! impl_int_float {
+    ($int:ty, $scale:expr) => {
+        impl SampleConvert<f32> for $int {
+            #[inline(always)]
+            fn convert(self) -> f32 {
+                (self as f64 * (1.0 / $scale)) as f32
+            }
+        }
+
+        impl SampleConvert<f64> for $int {
+            #[inline(always)]
+            fn convert(self) -> f64 {
+                self as f64 * (1.0 / $scale)
+            }
+        }
+
+        impl SampleConvert<$int> for f32 {
+            #[inline(always)]
+            fn convert(self) -> $int {
+                (self as f64).convert()
+            }
+
+            #[inline(always)]
+            unsafe fn convert_unchecked(self) -> $int {
+                (self as f64).convert_unchecked()
+            }
+        }
+
+        impl SampleConvert<$int> for f64 {
+            #[inline(always)]
+            fn convert(self) -> $int {
+                // Float-to-int `as` casts saturate, and NaN maps to zero.
+                round_offset(self * $scale) as $int
+            }
+
+            #[inline(always)]
+            unsafe fn convert_unchecked(self) -> $int {
+                // SAFETY: The caller upholds that the rounded value is representable
+                // in the integer type.
+                unsafe { round_offset(self * $scale).to_int_unchecked() }
+            }
+        }
+    };
+}
+
+impl_int_float!(i8, 128.0);
+impl_int_float!(i16, 32_768.0);
+impl_int_float!(i32, 2_147_483_648.0);
+
+macro_rules! impl_int_int {
+    ($from:ty, $to:ty, widen $shift:expr) => {
+        impl SampleConvert<$to> for $from {
+            #[inline(always)]
+            fn convert(self) -> $to {
+                (self as $to) << $shift
+            }
+        }
+
+        impl SampleConvert<$from> for $to {
+            #[inline(always)]
+            fn convert(self) -> $from {
+                (self >> $shift) as $from
+            }
+        }
+    };
+}
+
+impl_int_int!(i8, i16, widen 8);
+impl_int_int!(i8, i32, widen 24);
+impl_int_int!(i16, i32, widen 16);
+
+/// Convert each sample in `src` into the corresponding sample in `dst`, saturating
+/// any out-of-range values.
+///
+/// See [`SampleConvert`] for details on rounding and clipping.
+///
+/// Only `src.len().min(dst.len())` samples will be converted. Returns the number of
+/// samples that were converted.
+#[inline]
+pub fn convert_into_clamped<T: SampleConvert<U> + Copy, U>(src: &[T], dst: &mut [U]) -> usize {
+    let len = src.len().min(dst.len());
+
+    for (d, &s) in dst[..len].iter_mut().zip(&src[..len]) {
+        *d = s.convert();
+    }
+
+    len
+}
+
+/// Convert each sample in `src` into the corresponding sample in `dst` without
+/// clamping out-of-range values.
+///
+/// See [`SampleConvert::convert_unchecked`] for details.
+///
+/// Only `src.len().min(dst.len())` samples will be converted. Returns the number of
+/// samples that were converted.
+///
+/// # Safety
+/// Every sample in `src` must uphold the safety requirements of
+/// [`SampleConvert::convert_unchecked`].
+#[inline]
+pub unsafe fn convert_into_unchecked<T: SampleConvert<U> + Copy, U>(
+    src: &[T],
+    dst: &mut [U],
+) -> usize {
+    let len = src.len().min(dst.len());
+
+    for (d, &s) in dst[..len].iter_mut().zip(&src[..len]) {
+        // SAFETY: The caller upholds the safety requirements.
+        *d = unsafe { s.convert_unchecked() };
+    }
+
+    len
+}
@@ -0,0 +1,378 @@
+use core::ops::{Add, Div, Mul, Sub};
+
+use crate::{ChannelBufferRef, ChannelBufferRefMut};
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A floating-point sample type (`f32` or `f64`) that the DSP helper methods in this
+/// crate can operate on.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait Float:
+    sealed::Sealed
+    + Clone
+    + Copy
+    + Default
+    + Sized
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The value `0.0`.
+    const ZERO: Self;
+    /// The value `1.0`.
+    const ONE: Self;
+    /// The smallest positive normal value.
+    const MIN_POSITIVE: Self;
+
+    /// The absolute value of `self`.
+    fn abs(self) -> Self;
+
+    /// Convert an integer such as a channel count into this type.
+    fn from_usize(n: usize) -> Self;
+
+    /// The hyperbolic tangent of `self`.
+    fn tanh(self) -> Self;
+}
+
+impl Float for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const MIN_POSITIVE: Self = f32::MIN_POSITIVE;
+
+    #[inline(always)]
+    fn abs(self) -> Self {
+        f32::from_bits(self.to_bits() & !(1 << 31))
+    }
+
+    #[inline(always)]
+    fn from_usize(n: usize) -> Self {
+        n as f32
+    }
+
+    #[inline(always)]
+    fn tanh(self) -> Self {
+        libm::tanhf(self)
+    }
+}
+
+impl Float for f64 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+    const MIN_POSITIVE: Self = f64::MIN_POSITIVE;
+
+    #[inline(always)]
+    fn abs(self) -> Self {
+        f64::from_bits(self.to_bits() & !(1 << 63))
+    }
+
+    #[inline(always)]
+    fn from_usize(n: usize) -> Self {
+        n as f64
+    }
+
+    #[inline(always)]
+    fn tanh(self) -> Self {
+        libm::tanh(self)
+    }
+}
+
+/// Replace every subnormal sample in `s` with zero.
+#[inline]
+pub(crate) fn flush_denormals_slice<T: Float>(s: &mut [T]) {
+    for s in s.iter_mut() {
+        if s.abs() < T::MIN_POSITIVE {
+            *s = T::ZERO;
+        }
+    }
+}
+
+/// Get the largest absolute value of any sample in `s`, or `0` if `s` is empty.
+#[inline]
+pub(crate) fn peak_slice<T: Float>(s: &[T]) -> T {
+    s.iter().fold(T::ZERO, |peak, s| {
+        let a = s.abs();
+        if a > peak {
+            a
+        } else {
+            peak
+        }
+    })
+}
+
+/// Multiply every sample in `s` by `gain`.
+#[inline]
+pub(crate) fn apply_gain_slice<T: Float>(s: &mut [T], gain: T) {
+    for s in s.iter_mut() {
+        *s = *s * gain;
+    }
+}
+
+/// Add triangular-PDF noise in the range `(-amplitude, amplitude)` to every sample in
+/// `s`, where `rng` returns uniformly distributed values in the range `[0, 1)`.
+#[inline]
+pub(crate) fn tpdf_dither_slice<T: Float>(s: &mut [T], amplitude: T, rng: &mut impl FnMut() -> T) {
+    for s in s.iter_mut() {
+        // The difference of two uniform values has a triangular distribution.
+        let noise = rng() - rng();
+        *s = *s + noise * amplitude;
+    }
+}
+
+/// Apply `tanh(x * drive) / tanh(drive)` saturation to every sample in `s`.
+///
+/// This does nothing if `drive <= 0`.
+#[inline]
+pub(crate) fn soft_clip_slice<T: Float>(s: &mut [T], drive: T) {
+    if drive <= T::ZERO {
+        return;
+    }
+
+    let norm = T::ONE / drive.tanh();
+    for s in s.iter_mut() {
+        *s = (*s * drive).tanh() * norm;
+    }
+}
+
+/// A cheap rational approximation of `tanh(x)`, which is exact at `0` and reaches
+/// exactly `±1` at `x = ±3`.
+#[inline(always)]
+fn tanh_approx<T: Float>(x: T) -> T {
+    let three = T::from_usize(3);
+    let x = if x > three {
+        three
+    } else if x < T::ZERO - three {
+        T::ZERO - three
+    } else {
+        x
+    };
+
+    let x2 = x * x;
+    x * (T::from_usize(27) + x2) / (T::from_usize(27) + T::from_usize(9) * x2)
+}
+
+/// The same as [`soft_clip_slice`], but using [`tanh_approx`] instead of `tanh`.
+#[inline]
+pub(crate) fn soft_clip_fast_slice<T: Float>(s: &mut [T], drive: T) {
+    if drive <= T::ZERO {
+        return;
+    }
+
+    let norm = T::ONE / tanh_approx(drive);
+    for s in s.iter_mut() {
+        *s = tanh_approx(*s * drive) * norm;
+    }
+}
+
+/// Multiply the samples in `s` by a linear ramp rising from `0` to `1`.
+///
+/// The gain of sample `i` is `i / (s.len() - 1)`, so the first sample is always `0`
+/// and the last sample is left unchanged. A single sample is set to `0`.
+#[inline]
+pub(crate) fn fade_in_slice<T: Float>(s: &mut [T]) {
+    if s.len() <= 1 {
+        s.fill(T::ZERO);
+        return;
+    }
+
+    let step = T::ONE / T::from_usize(s.len() - 1);
+    for (i, s) in s.iter_mut().enumerate() {
+        *s = *s * (T::from_usize(i) * step);
+    }
+}
+
+/// Multiply the samples in `s` by a linear ramp falling from `1` to `0`.
+///
+/// This is the mirror image of [`fade_in_slice`], so the first sample is left
+/// unchanged and the last sample is always `0`. A single sample is set to `0`.
+#[inline]
+pub(crate) fn fade_out_slice<T: Float>(s: &mut [T]) {
+    let len = s.len();
+    if len <= 1 {
+        s.fill(T::ZERO);
+        return;
+    }
+
+    let step = T::ONE / T::from_usize(len - 1);
+    for (i, s) in s.iter_mut().enumerate() {
+        *s = *s * (T::from_usize(len - 1 - i) * step);
+    }
+}
+
+/// Resample `src` into `dst` using linear interpolation.
+///
+/// Sample `i` of `dst` is read from the fractional position `i * src.len() / dst.len()`
+/// in `src`, with the upper interpolation index clamped to the last sample of `src`.
+/// If `src` is empty, then `dst` is filled with zeros.
+#[inline]
+pub(crate) fn resample_linear_slice<T: Float>(dst: &mut [T], src: &[T]) {
+    let Some(last) = src.len().checked_sub(1) else {
+        dst.fill(T::ZERO);
+        return;
+    };
+    if dst.is_empty() {
+        return;
+    }
+
+    // The read position is tracked as an integer index plus a remainder out of
+    // `dst.len()` so that no precision is lost over long buffers.
+    let len = dst.len();
+    let (step, step_rem) = (src.len() / len, src.len() % len);
+    let denom = T::from_usize(len);
+    let mut i0 = 0;
+    let mut rem = 0;
+
+    for d in dst.iter_mut() {
+        let i1 = (i0 + 1).min(last);
+        let frac = T::from_usize(rem) / denom;
+        *d = src[i0] + (src[i1] - src[i0]) * frac;
+
+        i0 += step;
+        rem += step_rem;
+        if rem >= len {
+            rem -= len;
+            i0 += 1;
+        }
+    }
+}
+
+/// Write the average of every channel in `src` into `dst`.
+///
+/// Only the frames that overlap in both `src` and `dst` will be processed.
+#[cfg(feature = "alloc")]
+pub(crate) fn average_channels_into<T: Float, const CHANNELS: usize>(
+    src: &ChannelBufferRef<T, CHANNELS>,
+    dst: &mut [T],
+) {
+    let frames = dst.len().min(src.frames());
+    let dst = &mut dst[..frames];
+    let src = src.as_slices_with_length(frames);
+    let gain = T::ONE / T::from_usize(CHANNELS);
+
+    dst.copy_from_slice(src[0]);
+    for src_ch in &src[1..] {
+        for (d, &s) in dst.iter_mut().zip(src_ch.iter()) {
+            *d = *d + s;
+        }
+    }
+    for d in dst.iter_mut() {
+        *d = *d * gain;
+    }
+}
+
+/// Write the linear combination `a * src1 + b * src2` of two buffers into `dst`.
+///
+/// Only the frames that overlap in all three buffers will be processed.
+pub fn linear_combine_into<T, const CHANNELS: usize>(
+    dst: &mut ChannelBufferRefMut<T, CHANNELS>,
+    a: T,
+    src1: &ChannelBufferRef<T, CHANNELS>,
+    b: T,
+    src2: &ChannelBufferRef<T, CHANNELS>,
+) where
+    T: Clone + Copy + Default + Sized + Mul<Output = T> + Add<Output = T>,
+{
+    let frames = dst.frames().min(src1.frames()).min(src2.frames());
+
+    for ((dst_ch, src1_ch), src2_ch) in dst
+        .as_mut_slices_with_length(frames)
+        .into_iter()
+        .zip(src1.as_slices_with_length(frames))
+        .zip(src2.as_slices_with_length(frames))
+    {
+        for ((d, &x), &y) in dst_ch.iter_mut().zip(src1_ch).zip(src2_ch) {
+            *d = a * x + b * y;
+        }
+    }
+}
+
+/// The pan law used by the equal-power stereo panner, which determines the gain of
+/// each channel when a signal is panned to the center.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PanLaw {
+    /// Each channel is attenuated by -3dB at the center, so the total power stays
+    /// constant across the whole pan range.
+    #[default]
+    Minus3Db,
+    /// Each channel is left at unity gain at the center, so panning to the center
+    /// leaves the levels unchanged. Panning hard left or right boosts that side by
+    /// +3dB.
+    ZeroDb,
+}
+
+/// Get the `(left, right)` gains of an equal-power pan at `position`, where `-1.0`
+/// is hard left and `1.0` is hard right.
+///
+/// `position` is clamped to the range `[-1.0, 1.0]`.
+pub(crate) fn pan_gains(position: f32, law: PanLaw) -> (f32, f32) {
+    use core::f32::consts::FRAC_PI_4;
+
+    let angle = (position.clamp(-1.0, 1.0) + 1.0) * FRAC_PI_4;
+    let left = libm::cosf(angle);
+    let right = libm::sinf(angle);
+
+    match law {
+        PanLaw::Minus3Db => (left, right),
+        // Normalize by the gains at the center so that the center is exactly unity.
+        PanLaw::ZeroDb => (left / libm::cosf(FRAC_PI_4), right / libm::sinf(FRAC_PI_4)),
+    }
+}
+
+/// Scale the side (`L - R`) component of the stereo pair `left` and `right` by
+/// `width`, leaving the mid (`L + R`) component unchanged.
+///
+/// Only the frames that overlap in both channels are processed.
+pub(crate) fn stereo_width_slices(left: &mut [f32], right: &mut [f32], width: f32) {
+    for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+        let mid = (*l + *r) * 0.5;
+        let side = (*l - *r) * 0.5 * width;
+
+        *l = mid + side;
+        *r = mid - side;
+    }
+}
+
+/// Write an equal-power crossfade from `from` to `to` into `dst`.
+///
+/// Over the length of `dst`, `t` goes from `0.0` at the first frame to `1.0` at the
+/// last frame, and each frame is computed as
+/// `from * cos(t * π/2) + to * sin(t * π/2)`. The same gain is applied to every
+/// channel in a frame.
+///
+/// The gain curve always spans the full length of `dst`. If `from` or `to` has fewer
+/// frames than `dst`, then the missing samples are treated as silence.
+pub fn equal_power_crossfade_into<const CHANNELS: usize>(
+    dst: &mut ChannelBufferRefMut<f32, CHANNELS>,
+    from: &ChannelBufferRef<f32, CHANNELS>,
+    to: &ChannelBufferRef<f32, CHANNELS>,
+) {
+    let frames = dst.frames();
+    let step = if frames > 1 {
+        core::f32::consts::FRAC_PI_2 / (frames - 1) as f32
+    } else {
+        0.0
+    };
+
+    let mut dst = dst.as_mut_slices();
+    let from = from.as_slices();
+    let to = to.as_slices();
+
+    for frame_i in 0..frames {
+        let angle = frame_i as f32 * step;
+        let from_gain = libm::cosf(angle);
+        let to_gain = libm::sinf(angle);
+
+        for ((dst_ch, from_ch), to_ch) in dst.iter_mut().zip(from.iter()).zip(to.iter()) {
+            let x = from_ch.get(frame_i).copied().unwrap_or(0.0);
+            let y = to_ch.get(frame_i).copied().unwrap_or(0.0);
+            dst_ch[frame_i] = x * from_gain + y * to_gain;
+        }
+    }
+}
@@ -0,0 +1,127 @@
+#[cfg(feature = "alloc")]
+use crate::ChannelBuffer;
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+use crate::VarChannelBuffer;
+use crate::{ChannelBufferRef, ChannelBufferRefMut, SampleLayout};
+#[cfg(feature = "variable-channels")]
+use crate::{VarChannelBufferRef, VarChannelBufferRefMut};
+
+/// An object-safe interface to a buffer of samples with any number of channels.
+///
+/// This is implemented for both the fixed-channel and variable-channel buffer types,
+/// so that a function taking a `&dyn DynChannelBuffer<T>` can accept either family
+/// of buffers. The number of channels and frames are provided by [`SampleLayout`].
+pub trait DynChannelBuffer<T: Clone + Copy + Default + Sized>: SampleLayout<Sample = T> {
+    /// Get an immutable reference to the channel at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    fn channel(&self, index: usize) -> Option<&[T]>;
+}
+
+/// An object-safe interface to a mutable buffer of samples with any number of
+/// channels.
+///
+/// See [`DynChannelBuffer`].
+pub trait DynChannelBufferMut<T: Clone + Copy + Default + Sized>: DynChannelBuffer<T> {
+    /// Get a mutable reference to the channel at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    fn channel_mut(&mut self, index: usize) -> Option<&mut [T]>;
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> DynChannelBuffer<T>
+    for ChannelBufferRef<'a, T, CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        ChannelBufferRef::channel(self, index)
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> DynChannelBuffer<T>
+    for ChannelBufferRefMut<'a, T, CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        ChannelBufferRefMut::channel(self, index)
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> DynChannelBufferMut<T>
+    for ChannelBufferRefMut<'a, T, CHANNELS>
+{
+    #[inline]
+    fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        ChannelBufferRefMut::channel_mut(self, index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> DynChannelBuffer<T>
+    for ChannelBuffer<T, CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        ChannelBuffer::channel(self, index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> DynChannelBufferMut<T>
+    for ChannelBuffer<T, CHANNELS>
+{
+    #[inline]
+    fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        ChannelBuffer::channel_mut(self, index)
+    }
+}
+
+#[cfg(feature = "variable-channels")]
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> DynChannelBuffer<T>
+    for VarChannelBufferRef<'a, T, MAX_CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        VarChannelBufferRef::channel(self, index)
+    }
+}
+
+#[cfg(feature = "variable-channels")]
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> DynChannelBuffer<T>
+    for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        VarChannelBufferRefMut::channel(self, index)
+    }
+}
+
+#[cfg(feature = "variable-channels")]
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> DynChannelBufferMut<T>
+    for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
+{
+    #[inline]
+    fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        VarChannelBufferRefMut::channel_mut(self, index)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> DynChannelBuffer<T>
+    for VarChannelBuffer<T, MAX_CHANNELS>
+{
+    #[inline]
+    fn channel(&self, index: usize) -> Option<&[T]> {
+        VarChannelBuffer::channel(self, index)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> DynChannelBufferMut<T>
+    for VarChannelBuffer<T, MAX_CHANNELS>
+{
+    #[inline]
+    fn channel_mut(&mut self, index: usize) -> Option<&mut [T]> {
+        VarChannelBuffer::channel_mut(self, index)
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::TryReserveError;
+use core::fmt;
+use core::ops::Range;
+
+use crate::BufferShape;
+
+/// An error returned when creating an instance buffer or when accessing two instances
+/// of an instance buffer at once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstanceError {
+    /// The instance at `index` is out of bounds.
+    OutOfBounds { index: usize, num_instances: usize },
+    /// Both indices refer to the same instance.
+    SameInstance(usize),
+    /// The requested number of instances does not match the number of instances the
+    /// buffer type holds.
+    CountMismatch {
+        num_instances: usize,
+        instances: usize,
+    },
+    /// Allocating the buffer failed.
+    #[cfg(feature = "alloc")]
+    Alloc(TryReserveError),
+}
+
+impl fmt::Display for InstanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds {
+                index,
+                num_instances,
+            } => write!(
+                f,
+                "instance index {} is out of bounds for buffer with {} instances",
+                index, num_instances
+            ),
+            Self::SameInstance(index) => {
+                write!(f, "source and destination are both instance {}", index)
+            }
+            Self::CountMismatch {
+                num_instances,
+                instances,
+            } => write!(
+                f,
+                "requested {} instances for buffer type with {} instances",
+                num_instances, instances
+            ),
+            #[cfg(feature = "alloc")]
+            Self::Alloc(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<TryReserveError> for InstanceError {
+    fn from(e: TryReserveError) -> Self {
+        Self::Alloc(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InstanceError {}
+
+/// An error returned when the length of a data source does not match the layout of a
+/// buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutError {
+    /// The length of the data source.
+    pub len: usize,
+    /// The number of channels in the buffer.
+    pub channels: usize,
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "data length {} is not a multiple of the number of channels {}",
+            self.len, self.channels
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LayoutError {}
+
+/// An error returned when a range of frames is invalid or falls out of bounds of a
+/// buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeError {
+    /// The offending range.
+    pub range: Range<usize>,
+    /// The number of frames in the buffer.
+    pub frames: usize,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.range.start > self.range.end {
+            write!(
+                f,
+                "range start {} is greater than range end {}",
+                self.range.start, self.range.end
+            )
+        } else {
+            write!(
+                f,
+                "range end {} is out of bounds for buffer with {} frames",
+                self.range.end, self.frames
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+/// An error returned when changing the number of channels in a buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelCountError {
+    /// The requested number of channels is greater than the maximum number of channels
+    /// the buffer can hold.
+    ExceedsMaxChannels {
+        channels: usize,
+        max_channels: usize,
+    },
+    /// The length of the buffer's data is not a multiple of the requested number of
+    /// channels.
+    Layout(LayoutError),
+    /// The number of channels in the source does not match the number of channels in
+    /// the buffer.
+    Mismatch { expected: usize, actual: usize },
+}
+
+impl fmt::Display for ChannelCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExceedsMaxChannels {
+                channels,
+                max_channels,
+            } => write!(
+                f,
+                "{} channels exceeds the maximum of {} channels",
+                channels, max_channels
+            ),
+            Self::Layout(e) => e.fmt(f),
+            Self::Mismatch { expected, actual } => write!(
+                f,
+                "expected {} channels but got {} channels",
+                expected, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ChannelCountError {}
+
+/// An error returned when a buffer does not have enough allocated capacity for an
+/// operation which cannot allocate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// The number of samples that are required.
+    pub required: usize,
+    /// The number of samples the buffer has capacity for.
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} samples are required but the buffer only has capacity for {}",
+            self.required, self.capacity
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
+/// An error returned by the strict versions of operations between two buffers when
+/// the shapes of the buffers differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShapeMismatch {
+    /// The shape of the buffer being operated on.
+    pub lhs: BufferShape,
+    /// The shape of the other buffer.
+    pub rhs: BufferShape,
+}
+
+impl fmt::Display for ShapeMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer with {} channels and {} frames does not match buffer with {} channels and {} frames",
+            self.lhs.channels, self.lhs.frames, self.rhs.channels, self.rhs.frames
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ShapeMismatch {}
@@ -0,0 +1,271 @@
+use core::marker::PhantomData;
+use core::ops::Index;
+
+/// A mutable view into a single frame of a buffer, giving access to the sample in
+/// every channel at that frame.
+#[derive(Debug)]
+pub struct FrameMut<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
+    ptrs: [*mut T; CHANNELS],
+    _lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> FrameMut<'a, T, CHANNELS> {
+    /// Get an immutable reference to the sample in the channel at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= CHANNELS`.
+    #[inline(always)]
+    pub fn get(&self, index: usize) -> &T {
+        // SAFETY: The iterator that created this frame ensures that every pointer
+        // points to a valid sample for the lifetime `'a`.
+        unsafe { &*self.ptrs[index] }
+    }
+
+    /// Get a mutable reference to the sample in the channel at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= CHANNELS`.
+    #[inline(always)]
+    pub fn get_mut(&mut self, index: usize) -> &mut T {
+        // SAFETY:
+        // * The iterator that created this frame ensures that every pointer points to a
+        // valid sample for the lifetime `'a`, and that no other frame points to the same
+        // samples.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // sample can exist.
+        unsafe { &mut *self.ptrs[index] }
+    }
+
+    /// Set the sample in the channel at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index >= CHANNELS`.
+    #[inline(always)]
+    pub fn set(&mut self, index: usize, value: T) {
+        *self.get_mut(index) = value;
+    }
+
+    /// Get the samples of every channel in this frame as an array.
+    #[inline]
+    pub fn as_array(&self) -> [T; CHANNELS] {
+        // SAFETY: The iterator that created this frame ensures that every pointer
+        // points to a valid sample for the lifetime `'a`.
+        core::array::from_fn(|ch_i| unsafe { *self.ptrs[ch_i] })
+    }
+
+    /// Set the samples of every channel in this frame from an array.
+    #[inline]
+    pub fn set_from_array(&mut self, values: [T; CHANNELS]) {
+        for (ch_i, value) in values.into_iter().enumerate() {
+            self.set(ch_i, value);
+        }
+    }
+}
+
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `&mut [T]`, so it requires `T: Send`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
+    for FrameMut<'a, T, CHANNELS>
+where
+    T: Send,
+{
+}
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&mut [T]`, so it requires `T: Sync`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
+    for FrameMut<'a, T, CHANNELS>
+where
+    T: Sync,
+{
+}
+
+/// An iterator over mutable views into each frame of a buffer.
+///
+/// This is created by `frames_mut()`.
+#[derive(Debug)]
+pub struct FramesMut<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
+    offsets: [*mut T; CHANNELS],
+    frame: usize,
+    frames: usize,
+    _lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> FramesMut<'a, T, CHANNELS> {
+    /// # Safety
+    /// Every pointer in `offsets` must point to a unique, non-overlapping region of
+    /// `frames` samples which is valid and mutably borrowed for the lifetime `'a`.
+    #[inline(always)]
+    pub(crate) unsafe fn new(offsets: [*mut T; CHANNELS], frames: usize) -> Self {
+        Self {
+            offsets,
+            frame: 0,
+            frames,
+            _lifetime: PhantomData,
+        }
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Iterator
+    for FramesMut<'a, T, CHANNELS>
+{
+    type Item = FrameMut<'a, T, CHANNELS>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.frame >= self.frames {
+            return None;
+        }
+
+        // SAFETY:
+        // * The constructor upholds that every pointer points to a region of `frames`
+        // samples, and we have checked that `frame` is within bounds.
+        // * Each frame is only handed out once, so no two `FrameMut`s point to the same
+        // samples.
+        let ptrs = core::array::from_fn(|ch_i| unsafe { self.offsets[ch_i].add(self.frame) });
+        self.frame += 1;
+
+        Some(FrameMut {
+            ptrs,
+            _lifetime: PhantomData,
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.frames - self.frame;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> ExactSizeIterator
+    for FramesMut<'a, T, CHANNELS>
+{
+}
+
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `&mut [T]`, so it requires `T: Send`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
+    for FramesMut<'a, T, CHANNELS>
+where
+    T: Send,
+{
+}
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&mut [T]`, so it requires `T: Sync`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
+    for FramesMut<'a, T, CHANNELS>
+where
+    T: Sync,
+{
+}
+
+/// An immutable view into an interleaved copy of a buffer, giving contiguous access
+/// to the samples of every channel in each frame.
+///
+/// This is created by `transpose_into_interleaved_scratch()`.
+#[derive(Debug, Clone, Copy)]
+pub struct TransposedView<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> {
+    frames: &'a [[T; CHANNELS]],
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> TransposedView<'a, T, CHANNELS> {
+    /// Create a view into the interleaved samples in `data`. Any trailing partial
+    /// frame in `data` is ignored.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub(crate) fn new(data: &'a [T]) -> Self {
+        // SAFETY:
+        // * `[T; CHANNELS]` has the same alignment as `T`, and its samples are laid out
+        // contiguously with no padding.
+        // * The new slice covers the first `(data.len() / CHANNELS) * CHANNELS` samples
+        // of `data`, which are all within bounds.
+        let frames = unsafe {
+            core::slice::from_raw_parts(
+                data.as_ptr().cast::<[T; CHANNELS]>(),
+                data.len() / CHANNELS,
+            )
+        };
+
+        Self { frames }
+    }
+
+    /// The number of frames in this view.
+    #[inline(always)]
+    pub fn frames(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Get the samples of every channel in the frame at `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline(always)]
+    pub fn frame(&self, index: usize) -> Option<&'a [T; CHANNELS]> {
+        self.frames.get(index)
+    }
+
+    /// Get the samples of every channel in the frame at `index` without bounds
+    /// checking.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.frames()`.
+    #[inline(always)]
+    pub unsafe fn frame_unchecked(&self, index: usize) -> &'a [T; CHANNELS] {
+        self.frames.get_unchecked(index)
+    }
+
+    /// Get all frames as a single slice.
+    #[inline(always)]
+    pub fn as_frames(&self) -> &'a [[T; CHANNELS]] {
+        self.frames
+    }
+
+    /// Get the entire contents of the view as a single slice of interleaved samples.
+    #[inline(always)]
+    pub fn raw(&self) -> &'a [T] {
+        // SAFETY: `[T; CHANNELS]` has the same alignment as `T`, and its samples are
+        // laid out contiguously with no padding.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.frames.as_ptr().cast::<T>(),
+                self.frames.len() * CHANNELS,
+            )
+        }
+    }
+
+    /// Get an iterator over every frame in this view.
+    #[inline]
+    pub fn iter(&self) -> core::slice::Iter<'a, [T; CHANNELS]> {
+        self.frames.iter()
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Index<usize>
+    for TransposedView<'a, T, CHANNELS>
+{
+    type Output = [T; CHANNELS];
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.frames[index]
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> IntoIterator
+    for TransposedView<'a, T, CHANNELS>
+{
+    type Item = &'a [T; CHANNELS];
+    type IntoIter = core::slice::Iter<'a, [T; CHANNELS]>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.frames.iter()
+    }
+}
@@ -1,6 +1,15 @@
+use alloc::collections::TryReserveError;
+use alloc::vec::Vec;
+use core::ops::Range;
 use core::pin::Pin;
 
-use crate::{ChannelBufferRef, ChannelBufferRefMut};
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator};
+
+use crate::{ChannelBufferRef, ChannelBufferRefMut, InstanceError};
 
 /// A memory-efficient buffer of samples with a fixed compile-time number of instances each with a
 /// fixed compile-time number of `CHANNELS`. Each channel has a fixed runtime number of `frames`
@@ -25,7 +34,11 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
         assert!(CHANNELS > 0);
     };
 
+    /// The number of instances this buffer type holds.
+    pub const INSTANCES: usize = INSTANCES;
+
     /// Create an empty [`InstanceBuffer`] with no allocated capacity.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -46,9 +59,17 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
     /// of audio).
     ///
     /// All data will be initialized with the default value.
+    ///
+    /// # Panics
+    /// Panics if `num_instances` is not equal to `INSTANCES`.
+    #[allow(clippy::let_unit_value)]
     pub fn new(num_instances: usize, frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        if let Err(e) = Self::check_num_instances(num_instances) {
+            panic!("{e}");
+        }
+
         let instance_length = frames * CHANNELS;
         let buffer_len = instance_length * num_instances;
 
@@ -76,17 +97,63 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
         }
     }
 
+    /// Create a new [`InstanceChannelBuffer`] allocated with the given number of
+    /// `instances`, each with the given number of `frames` (samples in a single channel
+    /// of audio).
+    ///
+    /// All data will be initialized with the default value.
+    ///
+    /// Unlike [`InstanceChannelBuffer::new`], this returns an error instead of panicking
+    /// if `num_instances` is not equal to `INSTANCES`, and instead of aborting if the
+    /// allocation fails.
+    #[allow(clippy::let_unit_value)]
+    pub fn try_new(num_instances: usize, frames: usize) -> Result<Self, InstanceError> {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        Self::check_num_instances(num_instances)?;
+
+        let buffer_len = Self::checked_len(num_instances, frames)?;
+        let instance_length = frames * CHANNELS;
+
+        let mut data = Vec::<T>::new();
+        data.try_reserve_exact(buffer_len)?;
+        data.resize(buffer_len, Default::default());
+
+        let mut data = Pin::new(data);
+
+        let offsets = Self::build_offsets(
+            &mut data,
+            frames,
+            core::array::from_fn(|inst_i| instance_length * inst_i),
+        );
+
+        Ok(Self {
+            data,
+            offsets,
+            frames,
+            instance_length,
+        })
+    }
+
     /// Create a new [`InstanceChannelBuffer`] allocated with the given number of
     /// `instances`, each with the given number of `frames` (samples in a single channel
     /// of audio).
     ///
     /// No data will be initialized.
     ///
+    /// # Panics
+    /// Panics if `num_instances` is not equal to `INSTANCES`.
+    ///
     /// # Safety
     /// Any data must be initialized before reading.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_uninit(num_instances: usize, frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        if let Err(e) = Self::check_num_instances(num_instances) {
+            panic!("{e}");
+        }
+
         let instance_length = frames * CHANNELS;
         let buffer_len = instance_length * num_instances;
 
@@ -114,6 +181,92 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
         }
     }
 
+    /// Reserve capacity for at least `num_instances` instances each with `frames`
+    /// frames (samples in a single channel of audio) in total.
+    ///
+    /// This does not change the contents or the layout of the buffer. Returns an error
+    /// instead of aborting if the allocation fails.
+    pub fn try_reserve(
+        &mut self,
+        num_instances: usize,
+        frames: usize,
+    ) -> Result<(), TryReserveError> {
+        let required = Self::checked_len(num_instances, frames)?;
+        let additional = required.saturating_sub(self.data.len());
+
+        let starts = self.instance_starts();
+
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+        let res = data.try_reserve_exact(additional);
+        self.data = Pin::new(data);
+
+        // The vec may have been moved to a new allocation, so the offsets need to be
+        // rebuilt.
+        self.offsets = Self::build_offsets(&mut self.data, self.frames, starts);
+
+        res
+    }
+
+    /// Returns an error if `num_instances` is not equal to `INSTANCES`.
+    fn check_num_instances(num_instances: usize) -> Result<(), InstanceError> {
+        if num_instances != INSTANCES {
+            return Err(InstanceError::CountMismatch {
+                num_instances,
+                instances: INSTANCES,
+            });
+        }
+        Ok(())
+    }
+
+    /// Compute `num_instances * frames * CHANNELS`, returning a capacity overflow error
+    /// if it overflows.
+    fn checked_len(num_instances: usize, frames: usize) -> Result<usize, TryReserveError> {
+        match frames
+            .checked_mul(CHANNELS)
+            .and_then(|len| len.checked_mul(num_instances))
+        {
+            Some(len) => Ok(len),
+            // `TryReserveError` cannot be constructed directly, so get one from a
+            // reservation which always overflows.
+            None => Err(Vec::<u8>::new().try_reserve(usize::MAX).unwrap_err()),
+        }
+    }
+
+    /// Compute the pointers to each channel of each instance in `data`, where
+    /// `starts[i]` is the index into `data` of the first sample of instance `i`.
+    ///
+    /// Any pointers which fall outside of `data` are dangling and must not be
+    /// dereferenced.
+    fn build_offsets(
+        data: &mut Pin<Vec<T>>,
+        frames: usize,
+        starts: [usize; INSTANCES],
+    ) -> [[*mut T; CHANNELS]; INSTANCES] {
+        let ptr = data.as_mut_ptr();
+
+        core::array::from_fn(|inst_i| {
+            core::array::from_fn(|ch_i| ptr.wrapping_add(starts[inst_i] + (frames * ch_i)))
+        })
+    }
+
+    /// Get the index into the data vec of the first sample of each instance.
+    ///
+    /// This accounts for any reordering done by [`InstanceChannelBuffer::swap_instances`].
+    fn instance_starts(&self) -> [usize; INSTANCES] {
+        let base = self.data.as_ptr() as usize;
+        let size = core::mem::size_of::<T>().max(1);
+
+        core::array::from_fn(|inst_i| (self.offsets[inst_i][0] as usize - base) / size)
+    }
+
+    /// Returns `true` if every instance in `range` is still stored at its original
+    /// place in the data vec, and has not been moved by
+    /// [`InstanceChannelBuffer::swap_instances`].
+    fn instances_in_place(&self, mut range: Range<usize>) -> bool {
+        let starts = self.instance_starts();
+        range.all(|inst_i| starts[inst_i] == inst_i * self.instance_length)
+    }
+
     /// The number of instances in this buffer.
     pub fn num_instances(&self) -> usize {
         self.offsets.len()
@@ -130,6 +283,15 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
         self.frames
     }
 
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    ///
+    /// Because `INSTANCES` is asserted at compile-time to be non-zero, this buffer
+    /// can only be empty if each of its instances is empty.
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
     /// Get an immutable reference to the instance at the given index.
     ///
     /// Returns `None` if `index` is out of bounds.
@@ -213,15 +375,191 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
                 *self.offsets.get_unchecked(index).get_unchecked(0),
                 self.instance_length,
             ),
-            self.offsets.get_unchecked(index).clone(),
+            *self.offsets.get_unchecked(index),
             self.frames,
         )
     }
 
+    /// Get mutable references to two different instances at once.
+    ///
+    /// Returns an error if either index is out of bounds or if `a == b`.
+    pub fn instance_pair_mut<'a>(
+        &'a mut self,
+        a: usize,
+        b: usize,
+    ) -> Result<
+        (
+            ChannelBufferRefMut<'a, T, CHANNELS>,
+            ChannelBufferRefMut<'a, T, CHANNELS>,
+        ),
+        InstanceError,
+    > {
+        for index in [a, b] {
+            if index >= self.num_instances() {
+                return Err(InstanceError::OutOfBounds {
+                    index,
+                    num_instances: self.num_instances(),
+                });
+            }
+        }
+        if a == b {
+            return Err(InstanceError::SameInstance(a));
+        }
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `num_instances * frames * CHANNELS`.
+        // * We have checked that both `a` and `b` are within bounds.
+        // * The Vec is pinned and cannot be moved, so the pointers are valid for the lifetime
+        // of the struct.
+        // * `self` is borrowed as mutable, and we have checked that `a != b`, so the two
+        // instances do not overlap and all mutability rules are being upheld.
+        // * We have asserted at compile-time that both `INSTANCES` and `CHANNELS` are non-zero.
+        unsafe {
+            let get = |index: usize| {
+                ChannelBufferRefMut::from_raw(
+                    core::slice::from_raw_parts_mut(
+                        *self.offsets.get_unchecked(index).get_unchecked(0),
+                        self.instance_length,
+                    ),
+                    *self.offsets.get_unchecked(index),
+                    self.frames,
+                )
+            };
+
+            Ok((get(a), get(b)))
+        }
+    }
+
+    /// Swap the instances at indices `a` and `b`.
+    ///
+    /// This is an `O(1)` operation which reorders the instances logically without
+    /// moving any sample data. Note that [`InstanceChannelBuffer::raw`] and the other
+    /// methods which access the raw data directly still see the instances in their
+    /// original order in memory, and [`InstanceChannelBuffer::instances_range`] returns
+    /// `None` for any range containing a swapped instance.
+    ///
+    /// Returns `false` if either index is out of bounds.
+    pub fn swap_instances(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.num_instances() || b >= self.num_instances() {
+            return false;
+        }
+
+        self.offsets.swap(a, b);
+
+        true
+    }
+
+    /// Overwrite every sample in the instance at index `index` with the value returned
+    /// by `f`.
+    ///
+    /// `f` is called with the arguments `(channel_index, frame_index)`. The previous
+    /// contents of the instance are ignored.
+    ///
+    /// Returns an error if `index` is out of bounds.
+    pub fn fill_instance_from_fn(
+        &mut self,
+        index: usize,
+        f: impl FnMut(usize, usize) -> T,
+    ) -> Result<(), InstanceError> {
+        let num_instances = self.num_instances();
+        let mut instance = self.instance_mut(index).ok_or(InstanceError::OutOfBounds {
+            index,
+            num_instances,
+        })?;
+
+        instance.fill_from_fn(f);
+
+        Ok(())
+    }
+
+    /// Fill every sample in the instance at index `index` with `value`.
+    ///
+    /// Returns an error if `index` is out of bounds.
+    pub fn fill_instance(&mut self, index: usize, value: T) -> Result<(), InstanceError> {
+        let num_instances = self.num_instances();
+        let mut instance = self.instance_mut(index).ok_or(InstanceError::OutOfBounds {
+            index,
+            num_instances,
+        })?;
+
+        for ch in instance.as_mut_slices() {
+            ch.fill(value);
+        }
+
+        Ok(())
+    }
+
+    /// Copy the frames in `range` of every channel from the instance at index `src`
+    /// to the instance at index `dst`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be copied.
+    ///
+    /// Returns an error if either index is out of bounds or if `src == dst`.
+    pub fn copy_frames_between_instances(
+        &mut self,
+        src: usize,
+        dst: usize,
+        range: Range<usize>,
+    ) -> Result<(), InstanceError> {
+        let (src, mut dst) = self.instance_pair_mut(src, dst)?;
+
+        for (src_ch, dst_ch) in src
+            .as_slices_with_range(range.clone())
+            .into_iter()
+            .zip(dst.as_mut_slices_with_range(range))
+        {
+            dst_ch.copy_from_slice(src_ch);
+        }
+
+        Ok(())
+    }
+
+    /// Get the contiguous slice of raw data backing the instances in `range`.
+    ///
+    /// Instances are laid out one after the other in memory, so the returned slice has
+    /// a length of `range.len() * frames * CHANNELS`.
+    ///
+    /// Returns `None` if `range.start > range.end`, if `range.end` is out of bounds, or
+    /// if any of the instances in `range` have been moved out of their place in memory
+    /// by [`InstanceChannelBuffer::swap_instances`].
+    pub fn instances_range(&self, range: Range<usize>) -> Option<&[T]> {
+        if range.start > range.end
+            || range.end > self.num_instances()
+            || !self.instances_in_place(range.clone())
+        {
+            return None;
+        }
+
+        self.data
+            .get(range.start * self.instance_length..range.end * self.instance_length)
+    }
+
+    /// Get the contiguous mutable slice of raw data backing the instances in `range`.
+    ///
+    /// Instances are laid out one after the other in memory, so the returned slice has
+    /// a length of `range.len() * frames * CHANNELS`.
+    ///
+    /// Returns `None` if `range.start > range.end`, if `range.end` is out of bounds, or
+    /// if any of the instances in `range` have been moved out of their place in memory
+    /// by [`InstanceChannelBuffer::swap_instances`].
+    pub fn instances_range_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        if range.start > range.end
+            || range.end > self.num_instances()
+            || !self.instances_in_place(range.clone())
+        {
+            return None;
+        }
+
+        self.data
+            .get_mut(range.start * self.instance_length..range.end * self.instance_length)
+    }
+
     /// Get an immutable reference to all instances.
     pub fn all_instances<'a>(&'a self) -> [ChannelBufferRef<'a, T, CHANNELS>; INSTANCES] {
         // SAFETY: `inst_i` is always within bounds.
-        unsafe { std::array::from_fn(|inst_i| self.instance_unchecked(inst_i)) }
+        unsafe { core::array::from_fn(|inst_i| self.instance_unchecked(inst_i)) }
     }
 
     /// Get a mutable reference to all instances.
@@ -238,33 +576,113 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
         // mutability rules are being upheld.
         // * We have asserted at compile-time that both `INSTANCES` and `CHANNELS` are non-zero.
         unsafe {
-            std::array::from_fn(|inst_i| {
+            core::array::from_fn(|inst_i| {
                 ChannelBufferRefMut::from_raw(
                     core::slice::from_raw_parts_mut(
                         *self.offsets.get_unchecked(inst_i).get_unchecked(0),
                         self.instance_length,
                     ),
-                    self.offsets.get_unchecked(inst_i).clone(),
+                    *self.offsets.get_unchecked(inst_i),
                     self.frames,
                 )
             })
         }
     }
 
+    /// Get a parallel iterator over mutable references to all instances.
+    ///
+    /// Each instance occupies its own disjoint region of memory, so each instance can be
+    /// safely processed on a different thread. Note that the work done on each item must
+    /// be confined to that single instance.
+    #[cfg(feature = "rayon")]
+    pub fn par_instances_mut(
+        &mut self,
+    ) -> impl IndexedParallelIterator<Item = ChannelBufferRefMut<'_, T, CHANNELS>>
+    where
+        T: Send,
+    {
+        self.all_instances_mut().into_par_iter()
+    }
+
+    /// Allocate a new buffer with the same shape as this one, and call `f` with each
+    /// instance of this buffer along with the corresponding instance of the new buffer.
+    ///
+    /// The new buffer is initialized with the default value before `f` is called, so
+    /// any samples that `f` does not write to are left as the default value.
+    pub fn map_instances(
+        &self,
+        mut f: impl FnMut(ChannelBufferRef<'_, T, CHANNELS>, &mut ChannelBufferRefMut<'_, T, CHANNELS>),
+    ) -> Self {
+        let mut new_self = Self::new(self.num_instances(), self.frames);
+
+        for (src, mut dst) in self
+            .all_instances()
+            .into_iter()
+            .zip(new_self.all_instances_mut())
+        {
+            f(src, &mut dst);
+        }
+
+        new_self
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
     pub fn raw(&self) -> &[T] {
         &self.data
     }
 
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = INSTANCES * self.instance_length;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+
     /// Get the entire contents of the buffer as a single mutable slice.
     pub fn raw_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
 
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = INSTANCES * self.instance_length;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        let dst = self.as_bytes_mut();
+        let len = dst.len().min(bytes.len());
+        dst[..len].copy_from_slice(&bytes[..len]);
+        len
+    }
+
     /// Clear all data with the default value.
     pub fn clear(&mut self) {
         self.raw_mut().fill(T::default());
     }
+
+    /// Fill all data with `value`.
+    pub fn fill(&mut self, value: T) {
+        self.raw_mut().fill(value);
+    }
 }
 
 impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CHANNELS: usize>
@@ -275,6 +693,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CHANNELS: usize>
     Into<Vec<T>> for InstanceChannelBuffer<T, INSTANCES, CHANNELS>
 {
@@ -292,19 +711,123 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CH
 
         new_self.raw_mut().copy_from_slice(self.raw());
 
+        // Preserve any reordering done by `swap_instances`.
+        new_self.offsets =
+            Self::build_offsets(&mut new_self.data, self.frames, self.instance_starts());
+
         new_self
     }
 }
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `Vec<T>`, so it requires `T: Send`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CHANNELS: usize>
     Send for InstanceChannelBuffer<T, INSTANCES, CHANNELS>
+where
+    T: Send,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `Vec<T>`, so it requires `T: Sync`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const INSTANCES: usize, const CHANNELS: usize>
     Sync for InstanceChannelBuffer<T, INSTANCES, CHANNELS>
+where
+    T: Sync,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_new_rejects_instance_count_mismatch() {
+        assert_eq!(
+            InstanceChannelBuffer::<u32, 4, 2>::try_new(1, 4).err(),
+            Some(InstanceError::CountMismatch {
+                num_instances: 1,
+                instances: 4
+            })
+        );
+        assert!(InstanceChannelBuffer::<u32, 4, 2>::try_new(4, 4).is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn new_panics_on_instance_count_mismatch() {
+        let _ = InstanceChannelBuffer::<u32, 4, 2>::new(1, 4);
+    }
+
+    fn numbered() -> InstanceChannelBuffer<u32, 3, 2> {
+        let mut buf = InstanceChannelBuffer::<u32, 3, 2>::new(3, 2);
+        for inst_i in 0..3 {
+            buf.fill_instance(inst_i, inst_i as u32).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn swap_instances_reorders_views() {
+        let mut buf = numbered();
+
+        assert!(buf.swap_instances(0, 2));
+        assert_eq!(
+            buf.instance(0).unwrap().as_slices(),
+            [&[2, 2][..], &[2, 2][..]]
+        );
+        assert_eq!(
+            buf.instance(2).unwrap().as_slices(),
+            [&[0, 0][..], &[0, 0][..]]
+        );
+
+        // The raw data keeps its original order.
+        assert_eq!(buf.raw(), &[0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2]);
+
+        let cloned = buf.clone();
+        assert_eq!(
+            cloned.instance(0).unwrap().as_slices(),
+            [&[2, 2][..], &[2, 2][..]]
+        );
+
+        assert!(!buf.swap_instances(0, 3));
+    }
+
+    #[test]
+    fn instances_range_rejects_swapped_instances() {
+        let mut buf = numbered();
+
+        assert_eq!(buf.instances_range(1..3).unwrap().len(), 8);
+        assert!(buf.instances_range(2..4).is_none());
+
+        assert!(buf.swap_instances(1, 2));
+        assert!(buf.instances_range(0..2).is_none());
+        assert!(buf.instances_range_mut(1..3).is_none());
+        assert_eq!(buf.instances_range(0..1), Some(&[0, 0, 0, 0][..]));
+
+        assert!(buf.swap_instances(1, 2));
+        assert_eq!(
+            buf.instances_range(1..3).unwrap(),
+            &[1, 1, 1, 1, 2, 2, 2, 2]
+        );
+    }
+
+    #[test]
+    fn try_reserve_keeps_swapped_instances() {
+        let mut buf = numbered();
+        assert!(buf.swap_instances(0, 1));
+
+        buf.try_reserve(3, 16).unwrap();
+        assert_eq!(
+            buf.instance(0).unwrap().as_slices(),
+            [&[1, 1][..], &[1, 1][..]]
+        );
+        assert_eq!(
+            buf.instance(1).unwrap().as_slices(),
+            [&[0, 0][..], &[0, 0][..]]
+        );
+    }
+}
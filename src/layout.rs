@@ -0,0 +1,131 @@
+#[cfg(feature = "alloc")]
+use crate::ChannelBuffer;
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+use crate::VarChannelBuffer;
+use crate::{ChannelBufferRef, ChannelBufferRefMut, ShapeMismatch};
+#[cfg(feature = "variable-channels")]
+use crate::{VarChannelBufferRef, VarChannelBufferRefMut};
+
+/// The way the samples of a buffer are laid out in memory.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layout {
+    /// Each channel is stored contiguously, one after the other.
+    ///
+    /// All of the buffer types in this crate use this layout.
+    Planar,
+}
+
+/// The shape of a buffer (the number of channels and the number of frames in each
+/// channel).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BufferShape {
+    /// The number of channels.
+    pub channels: usize,
+    /// The number of frames (samples in a single channel of audio) in each channel.
+    pub frames: usize,
+}
+
+impl BufferShape {
+    /// Returns an error if this shape (the left-hand side of an operation) is not
+    /// the same as `rhs`.
+    pub fn check_same(self, rhs: BufferShape) -> Result<(), ShapeMismatch> {
+        if self == rhs {
+            Ok(())
+        } else {
+            Err(ShapeMismatch { lhs: self, rhs })
+        }
+    }
+}
+
+/// Which end of each channel the existing samples are aligned to when the number of
+/// frames in a buffer is changed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Anchor {
+    /// Keep the first frames of each channel, adding or removing frames at the end.
+    #[default]
+    Start,
+    /// Keep the last frames of each channel, adding or removing frames at the start.
+    End,
+}
+
+/// A buffer of samples with a number of channels each with a number of frames
+/// (samples in a single channel of audio), regardless of how those samples are laid
+/// out in memory.
+///
+/// This allows writing code which is generic over the layout of a buffer.
+pub trait SampleLayout {
+    /// The type of sample stored in the buffer.
+    type Sample: Clone + Copy + Default + Sized;
+
+    /// The way the samples of this buffer are laid out in memory.
+    fn layout(&self) -> Layout;
+
+    /// The number of channels in this buffer.
+    fn channels(&self) -> usize;
+
+    /// The number of frames (samples in a single channel of audio) in this buffer.
+    fn frames(&self) -> usize;
+
+    /// Get an immutable reference to the sample at the given channel and frame.
+    ///
+    /// Returns `None` if either index is out of bounds.
+    fn sample(&self, channel: usize, frame: usize) -> Option<&Self::Sample>;
+}
+
+macro_rules! impl_planar {
+    ($t:ty, [$($gen:tt)*]) => {
+        impl<$($gen)*> SampleLayout for $t {
+            type Sample = T;
+
+            #[inline(always)]
+            fn layout(&self) -> Layout {
+                Layout::Planar
+            }
+
+            #[inline(always)]
+            fn channels(&self) -> usize {
+                Self::channels(self).into()
+            }
+
+            #[inline(always)]
+            fn frames(&self) -> usize {
+                Self::frames(self)
+            }
+
+            #[inline]
+            fn sample(&self, channel: usize, frame: usize) -> Option<&T> {
+                self.channel(channel)?.get(frame)
+            }
+        }
+    };
+}
+
+impl_planar!(
+    ChannelBufferRef<'a, T, CHANNELS>,
+    ['a, T: Clone + Copy + Default + Sized, const CHANNELS: usize]
+);
+impl_planar!(
+    ChannelBufferRefMut<'a, T, CHANNELS>,
+    ['a, T: Clone + Copy + Default + Sized, const CHANNELS: usize]
+);
+#[cfg(feature = "alloc")]
+impl_planar!(
+    ChannelBuffer<T, CHANNELS>,
+    [T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize]
+);
+#[cfg(feature = "variable-channels")]
+impl_planar!(
+    VarChannelBufferRef<'a, T, MAX_CHANNELS>,
+    ['a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize]
+);
+#[cfg(feature = "variable-channels")]
+impl_planar!(
+    VarChannelBufferRefMut<'a, T, MAX_CHANNELS>,
+    ['a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize]
+);
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+impl_planar!(
+    VarChannelBuffer<T, MAX_CHANNELS>,
+    [T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize]
+);
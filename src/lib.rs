@@ -1,21 +1,121 @@
+//! A collection of memory-efficient audio buffer types for realtime applications.
+//!
+//! # Bounds checking
+//!
+//! Methods that access a buffer by index follow a consistent convention:
+//!
+//! * Accessors such as `channel()` and `instance()` return `None` if the index is out
+//!   of bounds.
+//! * Each of these has an `unsafe` `_unchecked` sibling (e.g. `channel_unchecked()`)
+//!   which performs no bounds checking.
+//! * Methods which take a range of frames (e.g. `as_slices_with_range()`) clamp the
+//!   range to the bounds of the buffer. Each of these has a strict `try_` sibling (e.g.
+//!   `try_as_slices_with_range()`) which instead returns a [`RangeError`].
+//! * Methods which mutate a buffer at a given index (e.g. `fill_channel_from_fn()`)
+//!   return `false` if the index is out of bounds, and methods which can fail in more
+//!   than one way return a `Result`.
+//! * `Index` implementations panic if the index is out of bounds.
+//!
+//! # Thread safety
+//!
+//! Each buffer type is `Send` and `Sync` under the same conditions as the standard
+//! type it most closely resembles:
+//!
+//! * The immutable ref types (e.g. [`ChannelBufferRef`]) behave like `&[T]`, and are
+//!   `Send` and `Sync` if `T: Sync`.
+//! * The mutable ref types (e.g. [`ChannelBufferRefMut`]) and frame views behave like
+//!   `&mut [T]`, and are `Send` if `T: Send` and `Sync` if `T: Sync`.
+//! * The owned buffer types (e.g. `ChannelBuffer`) behave like `Vec<T>`, and are
+//!   `Send` if `T: Send` and `Sync` if `T: Sync`.
+//!
+//! ```
+//! use audio_channel_buffer::{ChannelBufferRef, ChannelBufferRefMut};
+//!
+//! fn assert_send_sync<B: Send + Sync>() {}
+//!
+//! assert_send_sync::<ChannelBufferRef<'static, f32, 2>>();
+//! assert_send_sync::<ChannelBufferRefMut<'static, f32, 2>>();
+//! ```
+//!
+//! A buffer of samples which cannot be shared between threads cannot be sent to
+//! another thread by reference:
+//!
+//! ```compile_fail
+//! use audio_channel_buffer::ChannelBufferRef;
+//! use core::marker::PhantomData;
+//!
+//! #[derive(Clone, Copy, Default)]
+//! struct NotSync(PhantomData<*const ()>);
+//!
+//! fn assert_send<B: Send>() {}
+//!
+//! assert_send::<ChannelBufferRef<'static, NotSync, 2>>();
+//! ```
+//!
+//! And a mutable buffer of samples which cannot be sent to another thread cannot be
+//! sent either:
+//!
+//! ```compile_fail
+//! use audio_channel_buffer::ChannelBufferRefMut;
+//! use core::marker::PhantomData;
+//!
+//! #[derive(Clone, Copy, Default)]
+//! struct NotSend(PhantomData<*const ()>);
+//!
+//! fn assert_send<B: Send>() {}
+//!
+//! assert_send::<ChannelBufferRefMut<'static, NotSend, 2>>();
+//! ```
+
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+mod error;
+#[cfg(feature = "prefetch")]
+mod prefetch;
+mod util;
+
+pub use error::{
+    CapacityError, ChannelCountError, InstanceError, LayoutError, RangeError, ShapeMismatch,
+};
+
 pub(crate) mod const_buffer_ref;
-pub use const_buffer_ref::{ChannelBufferRef, ChannelBufferRefMut};
+pub use const_buffer_ref::{AsChannelBufferRef, ChannelBufferRef, ChannelBufferRefMut};
+
+mod dyn_buffer;
+pub use dyn_buffer::{DynChannelBuffer, DynChannelBufferMut};
+
+mod frame;
+pub use frame::{FrameMut, FramesMut, TransposedView};
+
+mod layout;
+pub use layout::{Anchor, BufferShape, Layout, SampleLayout};
+
+mod meter;
+pub use meter::SumOfSquares;
 
 #[cfg(feature = "variable-channels")]
 pub(crate) mod var_buffer_ref;
 #[cfg(feature = "variable-channels")]
 pub use var_buffer_ref::{VarChannelBufferRef, VarChannelBufferRefMut};
 
+#[cfg(feature = "variable-channels")]
+mod var_instance_buffer_ref;
+#[cfg(feature = "variable-channels")]
+pub use var_instance_buffer_ref::{VarInstanceChannelBufferRef, VarInstanceChannelBufferRefMut};
+
 #[cfg(feature = "alloc")]
 mod const_buffer;
 #[cfg(feature = "alloc")]
 pub use const_buffer::ChannelBuffer;
 
+#[cfg(feature = "alloc")]
+mod scratch;
+#[cfg(feature = "alloc")]
+pub use scratch::ScratchBuffer;
+
 #[cfg(all(feature = "alloc", feature = "variable-channels"))]
 mod var_buffer;
 #[cfg(all(feature = "alloc", feature = "variable-channels"))]
@@ -25,3 +125,18 @@ pub use var_buffer::VarChannelBuffer;
 mod instance_buffer;
 #[cfg(feature = "instance-buffer")]
 pub use instance_buffer::InstanceChannelBuffer;
+
+#[cfg(feature = "float")]
+mod dsp;
+#[cfg(feature = "float")]
+pub use dsp::{equal_power_crossfade_into, linear_combine_into, Float, PanLaw};
+
+#[cfg(feature = "float")]
+mod biquad;
+#[cfg(feature = "float")]
+pub use biquad::{Biquad, BiquadCoeffs, BiquadState};
+
+#[cfg(feature = "convert")]
+mod convert;
+#[cfg(feature = "convert")]
+pub use convert::{convert_into_clamped, convert_into_unchecked, SampleConvert};
@@ -0,0 +1,103 @@
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// A sample type whose squares can be summed without overflowing, used by the
+/// metering methods in this crate.
+///
+/// The accumulator type for each sample type is:
+///
+/// * `i8`, `i16`, `i32`, `u8`, `u16`, and `u32`: `u128`. Every square is computed
+///   exactly, and the sum cannot overflow for any buffer that fits in memory.
+/// * `f32` and `f64`: `f64`.
+///
+/// This trait is sealed and cannot be implemented outside of this crate.
+pub trait SumOfSquares: sealed::Sealed + Clone + Copy + Default + Sized {
+    /// The type that the squares of samples are accumulated in.
+    type Accumulator: Clone + Copy + Default;
+
+    /// Add the square of `self` to `acc`.
+    fn add_square(self, acc: Self::Accumulator) -> Self::Accumulator;
+
+    /// Convert an accumulated sum of squares into an `f64`.
+    fn accumulator_to_f64(acc: Self::Accumulator) -> f64;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl SumOfSquares for $t {
+                type Accumulator = u128;
+
+                #[inline(always)]
+                fn add_square(self, acc: u128) -> u128 {
+                    let s = self as i128;
+                    acc + (s * s) as u128
+                }
+
+                #[inline(always)]
+                fn accumulator_to_f64(acc: u128) -> f64 {
+                    acc as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i8, i16, i32, u8, u16, u32);
+
+impl SumOfSquares for f32 {
+    type Accumulator = f64;
+
+    #[inline(always)]
+    fn add_square(self, acc: f64) -> f64 {
+        let s = self as f64;
+        acc + s * s
+    }
+
+    #[inline(always)]
+    fn accumulator_to_f64(acc: f64) -> f64 {
+        acc
+    }
+}
+
+impl SumOfSquares for f64 {
+    type Accumulator = f64;
+
+    #[inline(always)]
+    fn add_square(self, acc: f64) -> f64 {
+        acc + self * self
+    }
+
+    #[inline(always)]
+    fn accumulator_to_f64(acc: f64) -> f64 {
+        acc
+    }
+}
+
+/// Get the sum of the squares of every sample in `s`.
+#[inline]
+pub(crate) fn sum_of_squares_slice<T: SumOfSquares>(s: &[T]) -> T::Accumulator {
+    s.iter()
+        .fold(T::Accumulator::default(), |acc, &s| s.add_square(acc))
+}
+
+/// Get the root mean square of the samples in `s`, or `0` if `s` is empty.
+#[cfg(feature = "float")]
+#[inline]
+pub(crate) fn rms_slice<T: SumOfSquares>(s: &[T]) -> f64 {
+    if s.is_empty() {
+        return 0.0;
+    }
+
+    libm::sqrt(T::accumulator_to_f64(sum_of_squares_slice(s)) / s.len() as f64)
+}
@@ -0,0 +1,42 @@
+/// Issue a software prefetch hint for the cache line containing `ptr`.
+///
+/// This is a no-op on architectures other than `x86`, `x86_64`, and `aarch64`, and on
+/// `x86` without the `sse` target feature.
+#[inline(always)]
+pub(crate) fn prefetch_read<T>(ptr: *const T) {
+    #[cfg(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse")
+    ))]
+    {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+        // SAFETY: Prefetching is only a hint and never faults, even if `ptr` is
+        // dangling. SSE is always available on `x86_64` and has been checked for on
+        // `x86`.
+        unsafe { _mm_prefetch::<_MM_HINT_T0>(ptr as *const i8) };
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        // SAFETY: Prefetching is only a hint and never faults, even if `ptr` is
+        // dangling.
+        unsafe {
+            core::arch::asm!(
+                "prfm pldl1keep, [{0}]",
+                in(reg) ptr,
+                options(nostack, readonly, preserves_flags)
+            );
+        }
+    }
+
+    #[cfg(not(any(
+        target_arch = "x86_64",
+        all(target_arch = "x86", target_feature = "sse"),
+        target_arch = "aarch64"
+    )))]
+    let _ = ptr;
+}
@@ -0,0 +1,53 @@
+use alloc::vec::Vec;
+
+/// A preallocated buffer of temporary samples used by methods which need
+/// intermediate storage.
+///
+/// Create this once ahead of time (i.e. outside of the realtime thread) and pass it
+/// into any method that requires it. Those methods will never allocate; if the scratch
+/// buffer is smaller than the data being processed, the data is processed in chunks.
+#[derive(Debug, Clone)]
+pub struct ScratchBuffer<T: Clone + Copy + Default + Sized> {
+    data: Vec<T>,
+}
+
+impl<T: Clone + Copy + Default + Sized> ScratchBuffer<T> {
+    /// Create a new [`ScratchBuffer`] which can hold `len` samples.
+    ///
+    /// All data will be initialized with the default value.
+    pub fn new(len: usize) -> Self {
+        let mut data = Vec::new();
+        data.reserve_exact(len);
+        data.resize(len, T::default());
+
+        Self { data }
+    }
+
+    /// Create a new [`ScratchBuffer`] which can hold the given number of frames
+    /// (samples in a single channel of audio) for every channel in a buffer with
+    /// `CHANNELS` channels.
+    ///
+    /// This is large enough to let methods process an entire buffer with the given
+    /// number of frames in a single chunk.
+    pub fn with_frames<const CHANNELS: usize>(frames: usize) -> Self {
+        Self::new(frames * CHANNELS)
+    }
+
+    /// The number of samples this buffer can hold.
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns `true` if this buffer cannot hold any samples.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Get the contents of this buffer as a mutable slice.
+    ///
+    /// This can be used to reuse the scratch space for other purposes.
+    #[inline(always)]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
@@ -0,0 +1,304 @@
+use core::ops::Range;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::Anchor;
+use crate::RangeError;
+
+/// Clamp every sample in `s` to the range `[min, max]`.
+#[inline]
+pub(crate) fn clamp_slice<T: Copy + PartialOrd>(s: &mut [T], min: T, max: T) {
+    for s in s.iter_mut() {
+        if *s < min {
+            *s = min;
+        } else if *s > max {
+            *s = max;
+        }
+    }
+}
+
+/// Returns an error if `range` is not a valid range within a buffer with `frames`
+/// frames.
+#[inline]
+pub(crate) fn check_range(range: &Range<usize>, frames: usize) -> Result<(), RangeError> {
+    if range.start > range.end || range.end > frames {
+        Err(RangeError {
+            range: range.clone(),
+            frames,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the `(min, max)` of the samples in `s` in a single pass, or the default value
+/// for both if `s` is empty.
+#[inline]
+pub(crate) fn min_max_slice<T: Copy + Default + PartialOrd>(s: &[T]) -> (T, T) {
+    let Some(&first) = s.first() else {
+        return (T::default(), T::default());
+    };
+
+    s[1..].iter().fold((first, first), |(min, max), &s| {
+        if s < min {
+            (s, max)
+        } else if s > max {
+            (min, s)
+        } else {
+            (min, max)
+        }
+    })
+}
+
+/// Clamp `range` to the bounds of a buffer with `frames` frames.
+///
+/// If `range.start > range.end`, then the returned range is empty.
+#[inline]
+pub(crate) fn clamped_frames(range: Range<usize>, frames: usize) -> Range<usize> {
+    let start = range.start.min(frames);
+    let end = range.end.min(frames).max(start);
+    start..end
+}
+
+/// Move every sample in `s` left by `n`, optionally filling the vacated samples at
+/// the end with the default value.
+#[inline]
+pub(crate) fn shift_slice_left<T: Copy + Default>(s: &mut [T], n: usize, clear: bool) {
+    let n = n.min(s.len());
+    s.copy_within(n.., 0);
+
+    if clear {
+        let len = s.len();
+        s[len - n..].fill(T::default());
+    }
+}
+
+/// Change the number of frames in each of the `channels` planar channels in `data`
+/// from `old_frames` to `new_frames` in place, keeping the samples at the given end
+/// of each channel and filling any new frames with the default value.
+///
+/// `data` must have a length of at least `channels * old_frames`, and will have a
+/// length of exactly `channels * new_frames` afterwards.
+#[cfg(feature = "alloc")]
+pub(crate) fn resize_planar<T: Copy + Default>(
+    data: &mut Vec<T>,
+    channels: usize,
+    old_frames: usize,
+    new_frames: usize,
+    anchor: Anchor,
+) {
+    let keep = old_frames.min(new_frames);
+    let (src_offset, dst_offset) = match anchor {
+        Anchor::Start => (0, 0),
+        Anchor::End => (old_frames - keep, new_frames - keep),
+    };
+
+    data.truncate(channels * old_frames);
+
+    if new_frames <= old_frames {
+        // Move the channels inward, starting with the first channel so that no
+        // channel is overwritten before it has been moved.
+        for ch_i in 0..channels {
+            let start = ch_i * old_frames + src_offset;
+            data.copy_within(start..start + keep, ch_i * new_frames + dst_offset);
+        }
+        data.truncate(channels * new_frames);
+    } else {
+        data.resize(channels * new_frames, T::default());
+
+        // Move the channels outward, starting with the last channel so that no
+        // channel is overwritten before it has been moved.
+        for ch_i in (0..channels).rev() {
+            let start = ch_i * old_frames + src_offset;
+            let dst_start = ch_i * new_frames + dst_offset;
+            data.copy_within(start..start + keep, dst_start);
+
+            data[ch_i * new_frames..dst_start].fill(T::default());
+            data[dst_start + keep..(ch_i + 1) * new_frames].fill(T::default());
+        }
+    }
+}
+
+/// Copy the interleaved samples in `src` into the planar channels in `dst`.
+///
+/// Only the frames that overlap in both `src` and `dst` will be copied, and any
+/// trailing partial frame in `src` will be ignored. `dst` must not be empty.
+#[inline]
+pub(crate) fn deinterleave<T: Copy>(src: &[T], dst: &mut [&mut [T]]) {
+    for (frame_i, frame) in src.chunks_exact(dst.len()).enumerate() {
+        for (ch, &s) in dst.iter_mut().zip(frame.iter()) {
+            let Some(d) = ch.get_mut(frame_i) else {
+                return;
+            };
+            *d = s;
+        }
+    }
+}
+
+/// Transpose the `rows x cols` row-major matrix in `data` into a `cols x rows`
+/// row-major matrix in place, such that the sample at `r * cols + c` is moved to
+/// `c * rows + r`.
+///
+/// This follows each cycle of the permutation, so every sample is moved exactly once.
+/// It allocates one bit per sample to track which samples have already been moved.
+///
+/// `data` must have a length of `rows * cols`.
+#[cfg(feature = "alloc")]
+pub(crate) fn transpose_in_place<T: Copy>(data: &mut [T], rows: usize, cols: usize) {
+    let len = data.len();
+    debug_assert_eq!(len, rows * cols);
+
+    if rows <= 1 || cols <= 1 {
+        return;
+    }
+
+    let dst_index = |i: usize| (i % cols) * rows + (i / cols);
+
+    let mut moved = alloc::vec![0u64; len.div_ceil(64)];
+
+    // The first and last samples never move.
+    for start in 1..len - 1 {
+        if moved[start / 64] & (1 << (start % 64)) != 0 {
+            continue;
+        }
+
+        let mut i = start;
+        let mut carried = data[start];
+        loop {
+            i = dst_index(i);
+            core::mem::swap(&mut data[i], &mut carried);
+            moved[i / 64] |= 1 << (i % 64);
+
+            if i == start {
+                break;
+            }
+        }
+    }
+}
+
+/// Copy the planar channels in `src` into `dst` as interleaved samples.
+///
+/// Every channel in `src` must have the same length, and `src` must not be empty.
+/// Only the frames that fit in `dst` will be copied, and any trailing partial frame
+/// in `dst` is left untouched.
+#[inline]
+pub(crate) fn interleave<T: Copy>(src: &[&[T]], dst: &mut [T]) {
+    for (frame_i, dst_frame) in dst.chunks_exact_mut(src.len()).enumerate() {
+        for (d, ch) in dst_frame.iter_mut().zip(src.iter()) {
+            let Some(&s) = ch.get(frame_i) else {
+                return;
+            };
+            *d = s;
+        }
+    }
+}
+
+/// The initial state of a 64-bit FNV-1a hash.
+#[cfg(feature = "bytemuck")]
+pub(crate) const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Continue a 64-bit FNV-1a hash with the given `bytes`.
+#[cfg(feature = "bytemuck")]
+#[inline]
+pub(crate) fn fnv1a_64(mut hash: u64, bytes: &[u8]) -> u64 {
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Find the index of the first frame in which the sample in any of the given channels
+/// satisfies `f`.
+#[inline]
+pub(crate) fn first_frame_where<'a, T: 'a>(
+    channels: impl IntoIterator<Item = &'a [T]>,
+    f: impl Fn(&T) -> bool,
+) -> Option<usize> {
+    let mut first = None;
+    for ch in channels {
+        // Only the frames before the current best need to be scanned.
+        let end = first.unwrap_or(ch.len()).min(ch.len());
+        if let Some(i) = ch[..end].iter().position(&f) {
+            first = Some(i);
+        }
+    }
+    first
+}
+
+/// Find the index of the last frame in which the sample in any of the given channels
+/// satisfies `f`.
+#[inline]
+pub(crate) fn last_frame_where<'a, T: 'a>(
+    channels: impl IntoIterator<Item = &'a [T]>,
+    f: impl Fn(&T) -> bool,
+) -> Option<usize> {
+    let mut last: Option<usize> = None;
+    for ch in channels {
+        // Only the frames after the current best need to be scanned.
+        let start = last.map_or(0, |i| i + 1).min(ch.len());
+        if let Some(i) = ch[start..].iter().rposition(&f) {
+            last = Some(start + i);
+        }
+    }
+    last
+}
+
+/// Copy the samples in `src` into `dst` in reverse order.
+///
+/// Only the first `n = dst.len().min(src.len())` samples of each slice are used, such
+/// that `dst[i] = src[n - 1 - i]`.
+#[inline]
+pub(crate) fn copy_reversed<T: Copy>(dst: &mut [T], src: &[T]) {
+    let n = dst.len().min(src.len());
+    for (d, &s) in dst[..n].iter_mut().zip(src[..n].iter().rev()) {
+        *d = s;
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn resize_planar_shrink() {
+        let mut data = vec![1, 2, 3, 4, 5, 6];
+        resize_planar(&mut data, 2, 3, 2, Anchor::Start);
+        assert_eq!(data, [1, 2, 4, 5]);
+
+        let mut data = vec![1, 2, 3, 4, 5, 6];
+        resize_planar(&mut data, 2, 3, 2, Anchor::End);
+        assert_eq!(data, [2, 3, 5, 6]);
+
+        let mut data = vec![1, 2, 3, 4, 5, 6];
+        resize_planar(&mut data, 2, 3, 0, Anchor::End);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn resize_planar_grow() {
+        let mut data = vec![1, 2, 3, 4];
+        resize_planar(&mut data, 2, 2, 3, Anchor::Start);
+        assert_eq!(data, [1, 2, 0, 3, 4, 0]);
+
+        let mut data = vec![1, 2, 3, 4];
+        resize_planar(&mut data, 2, 2, 3, Anchor::End);
+        assert_eq!(data, [0, 1, 2, 0, 3, 4]);
+
+        let mut data: Vec<u32> = vec![];
+        resize_planar(&mut data, 2, 0, 2, Anchor::End);
+        assert_eq!(data, [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn resize_planar_ignores_trailing_samples() {
+        let mut data = vec![1, 2, 3, 4, 9, 9];
+        resize_planar(&mut data, 2, 2, 3, Anchor::End);
+        assert_eq!(data, [0, 1, 2, 0, 3, 4]);
+    }
+}
@@ -1,11 +1,29 @@
+use alloc::boxed::Box;
 use alloc::vec::Vec;
 use core::num::NonZeroUsize;
-use core::ops::{Index, IndexMut, Range};
+use core::ops::{Index, IndexMut, MulAssign, Range};
 use core::pin::Pin;
 
 use arrayvec::ArrayVec;
-
-use crate::{VarChannelBufferRef, VarChannelBufferRefMut};
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+
+#[cfg(feature = "float")]
+use crate::dsp::{
+    apply_gain_slice, fade_in_slice, fade_out_slice, flush_denormals_slice, peak_slice,
+    soft_clip_fast_slice, soft_clip_slice, tpdf_dither_slice, Float,
+};
+#[cfg(feature = "prefetch")]
+use crate::prefetch::prefetch_read;
+use crate::util::{
+    check_range, clamp_slice, clamped_frames, deinterleave, interleave, resize_planar,
+    shift_slice_left,
+};
+use crate::var_buffer_ref::split_channels_at_mut;
+use crate::{
+    Anchor, BufferShape, ChannelBufferRef, ChannelBufferRefMut, ChannelCountError, LayoutError,
+    RangeError, VarChannelBufferRef, VarChannelBufferRefMut,
+};
 
 /// A memory-efficient buffer of samples with a fixed runtime number of channels each
 /// with a fixed runtime number of frames (samples in a single channel of audio).
@@ -25,7 +43,11 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         assert!(MAX_CHANNELS > 0);
     };
 
+    /// The maximum number of channels this buffer type can hold.
+    pub const MAX_CHANNELS: usize = MAX_CHANNELS;
+
     /// Create an empty [`VarChannelBuffer`] with no allocated capacity.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -49,6 +71,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     ///
     /// # Panics
     /// Panics if `channels.get() > MAX_CHANNELS`.
+    #[allow(clippy::let_unit_value)]
     pub fn new(channels: NonZeroUsize, frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -91,6 +114,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     ///
     /// # Panics
     /// Panics if `channels.get() > MAX_CHANNELS`.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_uninit(channels: NonZeroUsize, frames: usize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -122,18 +146,470 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Create a new [`VarChannelBuffer`] with the given number of frames and with one
+    /// channel for each value in `values`, where every sample in channel `i` is set to
+    /// `values[i]`.
+    ///
+    /// This is useful for building test signals with easily checked contents.
+    ///
+    /// # Panics
+    /// Panics if `values` is empty or if `values.len() > MAX_CHANNELS`.
+    pub fn from_channel_values(frames: usize, values: &[T]) -> Self {
+        let channels = NonZeroUsize::new(values.len()).expect("`values` must not be empty");
+
+        let mut buf = Self::new(channels, frames);
+        for (ch, &value) in buf.as_mut_slices().into_iter().zip(values) {
+            ch.fill(value);
+        }
+        buf
+    }
+
+    /// Increase the number of channels in this buffer to `channels`, reallocating if
+    /// needed.
+    ///
+    /// Existing channels keep their data, and all new channels are filled with the
+    /// default value. If `channels` is less than or equal to the current number of
+    /// channels, then this does nothing.
+    ///
+    /// Returns an error if `channels.get() > MAX_CHANNELS`.
+    ///
+    /// # Panics
+    /// Panics if the new size overflows or exceeds the maximum capacity of a `Vec`. The
+    /// buffer is left unchanged in that case.
+    pub fn grow_channels(&mut self, channels: NonZeroUsize) -> Result<(), ChannelCountError> {
+        if channels.get() > MAX_CHANNELS {
+            return Err(ChannelCountError::ExceedsMaxChannels {
+                channels: channels.get(),
+                max_channels: MAX_CHANNELS,
+            });
+        }
+
+        let old_channels = self.offsets.len();
+        if channels.get() <= old_channels {
+            return Ok(());
+        }
+
+        // Each channel keeps its region of `frames` samples, so the existing data does
+        // not need to be moved within the vec. Only the new channels need to be
+        // appended.
+        let required = channels
+            .get()
+            .checked_mul(self.frames)
+            .expect("capacity overflow");
+
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+        data.truncate(old_channels * self.frames);
+        if let Err(e) = data.try_reserve_exact(required - data.len()) {
+            // Put the data back before panicking so that the offsets stay valid.
+            self.data = Pin::new(data);
+            panic!("{e}");
+        }
+        data.resize(required, T::default());
+
+        self.data = Pin::new(data);
+
+        self.offsets.clear();
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have constrained `channels` above.
+        unsafe {
+            for ch_i in 0..channels.get() {
+                self.offsets
+                    .push_unchecked(self.data.as_mut_ptr().add(ch_i * self.frames));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decrease the number of channels in this buffer to `channels`, keeping the first
+    /// `channels` channels.
+    ///
+    /// This does not reallocate, so the memory of the dropped channels stays allocated.
+    /// Use [`VarChannelBuffer::compact_channels`] afterwards to free it. If `channels`
+    /// is greater than or equal to the current number of channels, then this does
+    /// nothing.
+    pub fn truncate_channels(&mut self, channels: NonZeroUsize) {
+        self.offsets.truncate(channels.get());
+
+        // Truncating does not reallocate, so the offsets stay valid.
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+        data.truncate(self.offsets.len() * self.frames);
+        self.data = Pin::new(data);
+    }
+
+    /// Shrink the allocation of this buffer so that it only holds the data of the
+    /// current channels, freeing any memory left over from dropped channels (e.g. after
+    /// [`VarChannelBuffer::truncate_channels`]) or from the spare capacity of the `Vec`
+    /// passed to [`VarChannelBuffer::from_raw_parts`].
+    ///
+    /// Each channel keeps its data. This may reallocate.
+    pub fn compact_channels(&mut self) {
+        let len = self.offsets.len() * self.frames;
+
+        // The channels are stored one after the other from the start of the vec, so
+        // the data of the current channels is already packed at the front and only the
+        // tail needs to be released.
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+        data.truncate(len);
+        data.shrink_to_fit();
+
+        self.data = Pin::new(data);
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * The number of channels is unchanged, so it is still within `MAX_CHANNELS`.
+        unsafe {
+            for (ch_i, ptr) in self.offsets.iter_mut().enumerate() {
+                *ptr = self.data.as_mut_ptr().add(ch_i * self.frames);
+            }
+        }
+    }
+
+    /// Change the number of frames in each channel, keeping the samples at the given
+    /// `anchor` end of each channel.
+    ///
+    /// With [`Anchor::Start`], frames are added or removed at the end of each channel.
+    /// With [`Anchor::End`], frames are added or removed at the start of each channel.
+    /// Any new frames are filled with the default value.
+    ///
+    /// This will allocate if the new size is larger than the allocated capacity.
+    ///
+    /// # Panics
+    /// Panics if the new size overflows or exceeds the maximum capacity of a `Vec`. The
+    /// buffer is left unchanged in that case.
+    pub fn resize_frames_with(&mut self, frames: usize, anchor: Anchor) {
+        let channels = self.offsets.len();
+
+        let required = frames.checked_mul(channels).expect("capacity overflow");
+
+        let mut data = Pin::into_inner(core::mem::replace(&mut self.data, Pin::new(Vec::new())));
+
+        data.truncate(self.frames * channels);
+        if let Err(e) = data.try_reserve_exact(required.saturating_sub(data.len())) {
+            // Put the data back before panicking so that the offsets stay valid.
+            self.data = Pin::new(data);
+            panic!("{e}");
+        }
+        resize_planar(&mut data, channels, self.frames, frames, anchor);
+
+        self.data = Pin::new(data);
+        self.frames = frames;
+
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * The number of channels is unchanged, so it is still within `MAX_CHANNELS`.
+        unsafe {
+            for (ch_i, ptr) in self.offsets.iter_mut().enumerate() {
+                *ptr = self.data.as_mut_ptr().add(ch_i * frames);
+            }
+        }
+    }
+
+    /// Reinterpret the data in this buffer as having the given number of channels.
+    ///
+    /// The data itself is left untouched, and the number of frames becomes
+    /// `self.channels() * self.frames() / channels`. Note that this changes which samples belong to which
+    /// channel: it is a reinterpretation of the memory layout, not a remix.
+    ///
+    /// Returns an error if `channels.get() > MAX_CHANNELS`, or if the length of the
+    /// data is not a multiple of `channels`.
+    pub fn reshape_channels(&mut self, channels: NonZeroUsize) -> Result<(), ChannelCountError> {
+        if channels.get() > MAX_CHANNELS {
+            return Err(ChannelCountError::ExceedsMaxChannels {
+                channels: channels.get(),
+                max_channels: MAX_CHANNELS,
+            });
+        }
+
+        let len = self.offsets.len() * self.frames;
+        let frames = len / channels.get();
+        if frames * channels.get() != len {
+            return Err(ChannelCountError::Layout(LayoutError {
+                len,
+                channels: channels.get(),
+            }));
+        }
+
+        self.offsets.clear();
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have constrained `channels` above.
+        unsafe {
+            for ch_i in 0..channels.get() {
+                self.offsets
+                    .push_unchecked(self.data.as_mut_ptr().add(ch_i * frames));
+            }
+        }
+        self.frames = frames;
+
+        Ok(())
+    }
+
+    /// Create a new [`VarChannelBuffer`] with the given number of channels which uses
+    /// the given `Vec` as its data, without copying.
+    ///
+    /// The data must be in planar layout, with each channel stored one after the other.
+    /// The number of frames will be `data.len() / channels`.
+    ///
+    /// Returns an error if `channels.get() > MAX_CHANNELS`, or if `data.len()` is not a
+    /// multiple of `channels`.
+    pub fn try_from_vec(data: Vec<T>, channels: NonZeroUsize) -> Result<Self, ChannelCountError> {
+        if channels.get() > MAX_CHANNELS {
+            return Err(ChannelCountError::ExceedsMaxChannels {
+                channels: channels.get(),
+                max_channels: MAX_CHANNELS,
+            });
+        }
+
+        let len = data.len();
+        let frames = len / channels.get();
+        if frames * channels.get() != len {
+            return Err(ChannelCountError::Layout(LayoutError {
+                len,
+                channels: channels.get(),
+            }));
+        }
+
+        Ok(Self::from_raw_parts(data, channels, frames))
+    }
+
+    /// Create a new [`VarChannelBuffer`] from a `Vec`, a number of channels, and a
+    /// number of frames previously returned by [`VarChannelBuffer::into_raw_parts`],
+    /// without copying.
+    ///
+    /// The data must be in planar layout, with each channel stored one after the other.
+    /// Any samples after the first `channels * frames` samples are dropped without
+    /// reallocating, so the capacity of the `Vec` is kept.
+    ///
+    /// # Panics
+    /// Panics if:
+    /// * `channels.get() > MAX_CHANNELS`
+    /// * `channels.get() * frames` overflows
+    /// * `data.len() < channels.get() * frames`
+    #[allow(clippy::let_unit_value)]
+    pub fn from_raw_parts(mut data: Vec<T>, channels: NonZeroUsize, frames: usize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        assert!(channels.get() <= MAX_CHANNELS);
+        assert!(channels
+            .get()
+            .checked_mul(frames)
+            .is_some_and(|n| data.len() >= n));
+
+        data.truncate(channels.get() * frames);
+
+        let mut data = Pin::new(data);
+
+        let mut offsets = ArrayVec::new();
+        // SAFETY:
+        // * All of these pointers point to valid memory in the vec.
+        // * We have constrained `channels` above.
+        unsafe {
+            for ch_i in 0..channels.get() {
+                offsets.push_unchecked(data.as_mut_ptr().add(ch_i * frames));
+            }
+        }
+
+        Self {
+            data,
+            offsets,
+            frames,
+        }
+    }
+
+    /// Consume this buffer and return the backing `Vec` along with the number of
+    /// channels and the number of frames in each channel.
+    ///
+    /// This is the only way to move the allocation out of the buffer, since the buffer
+    /// holds pointers into it. Use [`VarChannelBuffer::from_raw_parts`] to move it back
+    /// into a buffer.
+    pub fn into_raw_parts(self) -> (Vec<T>, NonZeroUsize, usize) {
+        let channels = self.channels();
+        (Pin::into_inner(self.data), channels, self.frames)
+    }
+
+    /// Consume this buffer and return its samples as a boxed slice in planar layout,
+    /// with each channel stored one after the other.
+    ///
+    /// Unlike converting into a `Vec`, this drops any spare capacity, along with any
+    /// memory left over from dropped channels (e.g. after
+    /// [`VarChannelBuffer::truncate_channels`]). This may reallocate.
+    pub fn into_boxed_slice(self) -> Box<[T]> {
+        let (mut data, channels, frames) = self.into_raw_parts();
+        data.truncate(frames * channels.get());
+        data.into_boxed_slice()
+    }
+
     /// The number of channels in this buffer.
     pub fn channels(&self) -> NonZeroUsize {
         // SAFETY: The constructors ensure that there is at least one element in `offsets`.
         unsafe { NonZeroUsize::new_unchecked(self.offsets.len()) }
     }
 
+    /// The maximum number of channels this buffer can hold (the `MAX_CHANNELS` const
+    /// generic parameter).
+    pub fn max_channels(&self) -> usize {
+        MAX_CHANNELS
+    }
+
     /// The number of frames (samples in a single channel of audio) that are allocated
     /// in this buffer.
     pub fn frames(&self) -> usize {
         self.frames
     }
 
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.as_ref().first_nonzero_frame()
+    }
+
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.as_ref().last_nonzero_frame()
+    }
+
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        self.as_ref().first_frame_above(threshold)
+    }
+
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        self.as_ref().last_frame_above(threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        self.as_ref().frame_energy_into(dst)
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Copy the interleaved samples in `interleaved` into the frames of this buffer
+    /// starting at `dst_start_frame`.
+    ///
+    /// This is useful for reading from an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in this buffer will be copied, and any
+    /// trailing partial frame in `interleaved` will be ignored. Returns the number of
+    /// frames that were copied.
+    pub fn deinterleave_into_at(&mut self, interleaved: &[T], dst_start_frame: usize) -> usize {
+        let channels = self.offsets.len();
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(interleaved.len() / channels);
+
+        deinterleave(
+            &interleaved[..frames * channels],
+            &mut self.as_mut_slices_with_range(start..start + frames),
+        );
+
+        frames
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let channels = self.offsets.len();
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / channels);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * channels],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -149,6 +625,18 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -167,6 +655,21 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -182,6 +685,18 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Get a mutable reference to the given range of frames in the channel at `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range_mut(&mut self, index: usize, range: Range<usize>) -> Option<&mut [T]> {
+        let ch = self.channel_mut(index)?;
+        let range = clamped_frames(range, ch.len());
+        Some(&mut ch[range])
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -202,6 +717,56 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         core::slice::from_raw_parts_mut(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get a mutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRefMut`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer_mut(&mut self, index: usize) -> Option<ChannelBufferRefMut<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel_mut(index)?;
+        let ptr = ch.as_mut_ptr();
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRefMut::from_raw(ch, [ptr], frames)) }
+    }
+
+    /// Get mutable references to the channels at each of the given `indices` at once.
+    /// Each slice will have a length of `self.frames()`.
+    ///
+    /// Returns `None` if any index is out of bounds or if any index appears more than
+    /// once.
+    #[inline]
+    pub fn channels_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut [T]; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.offsets.len() || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * We have checked above that every index is within bounds and that no index
+        // appears more than once, so none of the returned slices overlap.
+        // * The Vec is pinned and cannot be moved, so the pointers are valid for the lifetime
+        // of the struct.
+        // * `self` is borrowed as mutable, so all mutability rules are being upheld.
+        unsafe {
+            Some(core::array::from_fn(|i| {
+                core::slice::from_raw_parts_mut(
+                    *self.offsets.get_unchecked(indices[i]),
+                    self.frames,
+                )
+            }))
+        }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> ArrayVec<&[T], MAX_CHANNELS> {
@@ -223,6 +788,31 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get an iterator over all channels as immutable slices, from the first channel to
+    /// the last. Each slice will have a length of `self.frames()`.
+    ///
+    /// The iterator is double-ended, so `.rev()` can be used to iterate back-to-front.
+    #[inline]
+    pub fn channels_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        let frames = self.frames;
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `frames * self.channels()`.
+        // * The data vec cannot be moved, so the pointers are valid for the lifetime
+        // of the vec.
+        self.offsets
+            .iter()
+            .map(move |ptr| unsafe { core::slice::from_raw_parts(*ptr, frames) })
+    }
+
+    /// Get an iterator over all channels as immutable slices, from the last channel to
+    /// the first. Each slice will have a length of `self.frames()`.
+    #[inline]
+    pub fn channels_iter_rev(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        self.channels_iter().rev()
+    }
+
     /// Get all channels as mutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_mut_slices(&mut self) -> ArrayVec<&mut [T], MAX_CHANNELS> {
@@ -246,6 +836,47 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get an iterator over all channels as mutable slices, from the first channel to
+    /// the last. Each slice will have a length of `self.frames()`.
+    ///
+    /// The iterator is double-ended, so `.rev()` can be used to iterate back-to-front.
+    #[inline]
+    pub fn channels_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator + '_ {
+        let frames = self.frames;
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data vec has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * The data vec cannot be moved, so the pointers are valid for the lifetime
+        // of the vec.
+        // * `self` is borrowed as mutable for the lifetime of the iterator, so all
+        // mutability rules are being upheld.
+        self.offsets
+            .iter()
+            .map(move |ptr| unsafe { core::slice::from_raw_parts_mut(*ptr, frames) })
+    }
+
+    /// Get an iterator over all channels as mutable slices, from the last channel to
+    /// the first. Each slice will have a length of `self.frames()`.
+    #[inline]
+    pub fn channels_iter_rev_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [T]> + '_ {
+        self.channels_iter_mut().rev()
+    }
+
+    /// Get an iterator over all channels as mutable slices paired with their channel
+    /// index. Each slice will have a length of `self.frames()`.
+    ///
+    /// This is useful for dispatching per-channel processing (such as automation
+    /// curves) by channel index.
+    #[inline]
+    pub fn enumerate_channels_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &mut [T])> + ExactSizeIterator + '_ {
+        self.channels_iter_mut().enumerate()
+    }
+
     /// Get all channels as immutable slices with the given length in frames.
     ///
     /// If `frames > self.frames()`, then each slice will have a length of `self.frames()`
@@ -307,7 +938,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> ArrayVec<&[T], MAX_CHANNELS> {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         let mut v = ArrayVec::new();
 
@@ -328,6 +959,19 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`VarChannelBuffer::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<ArrayVec<&[T], MAX_CHANNELS>, RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
     /// Get all channels as mutable slices in the given range.
     ///
     /// If all or part of the range falls out of bounds, then only the part that falls
@@ -338,7 +982,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         range: Range<usize>,
     ) -> ArrayVec<&mut [T], MAX_CHANNELS> {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         let mut v = ArrayVec::new();
 
@@ -364,16 +1008,92 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get all channels as mutable slices in the given range.
+    ///
+    /// Unlike [`VarChannelBuffer::as_mut_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_mut_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<ArrayVec<&mut [T], MAX_CHANNELS>, RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_mut_slices_with_range(range))
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
     pub fn raw(&self) -> &[T] {
         &self.data
     }
 
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+
     /// Get the entire contents of the buffer as a single mutable slice.
     pub fn raw_mut(&mut self) -> &mut [T] {
         &mut self.data
     }
 
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.frames;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        let dst = self.as_bytes_mut();
+        let len = dst.len().min(bytes.len());
+        dst[..len].copy_from_slice(&bytes[..len]);
+        len
+    }
+
     /// Clear all data with the default value.
     pub fn clear(&mut self) {
         self.raw_mut().fill(T::default());
@@ -386,6 +1106,232 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Clear all data in the channel at `index` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel(&mut self, index: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        ch.fill(T::default());
+        true
+    }
+
+    /// Clear all data in the channel at `index` up to `frames` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel_frames(&mut self, index: usize, frames: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        let frames = frames.min(ch.len());
+        ch[..frames].fill(T::default());
+        true
+    }
+
+    /// Move the samples in every channel left by `n` frames, discarding the first `n`
+    /// frames.
+    ///
+    /// If `clear` is `true`, then the last `n` frames will be filled with the default
+    /// value. Otherwise their contents are left unchanged (stale).
+    ///
+    /// Returns the number of frames at the start of the buffer that still contain
+    /// valid (shifted) data.
+    pub fn shift_frames_left(&mut self, n: usize, clear: bool) -> usize {
+        for ch in self.as_mut_slices() {
+            shift_slice_left(ch, n, clear);
+        }
+
+        self.frames - n.min(self.frames)
+    }
+
+    /// Copy each slice in `planes` into the corresponding channel of this buffer.
+    ///
+    /// Only the frames that overlap in both the plane and the channel will be copied.
+    ///
+    /// Returns an error if `planes.len() != self.channels().get()`.
+    pub fn copy_from_planes(&mut self, planes: &[&[T]]) -> Result<(), ChannelCountError> {
+        self.as_mut().copy_from_planes(planes)
+    }
+
+    /// Clamp every sample in each channel to the range `[min, max]`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp(&mut self, min: T, max: T)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices() {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Clamp every sample in each channel within the given range of frames to the
+    /// range `[min, max]`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be clamped.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp_range(&mut self, min: T, max: T, range: Range<usize>)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices_with_range(range) {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Apply a linear fade in to the first `frames` frames of every channel, ramping
+    /// from a gain of `0` at the first frame up to `1` at the last faded frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_in(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let frames = frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_in_slice(&mut ch[..frames]);
+        }
+    }
+
+    /// Apply a linear fade out to the last `frames` frames of every channel, ramping
+    /// from a gain of `1` at the first faded frame down to `0` at the last frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_out(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let start = self.frames - frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_out_slice(&mut ch[start..]);
+        }
+    }
+
+    /// Scale every channel by a single gain so that the peak absolute value of any
+    /// sample in this buffer becomes `target_peak`, preserving the balance between
+    /// channels.
+    ///
+    /// Returns the gain that was applied. If every sample is silent, then this does
+    /// nothing and returns a gain of `1`.
+    #[cfg(feature = "float")]
+    pub fn normalize(&mut self, target_peak: T) -> T
+    where
+        T: Float,
+    {
+        let peak = self.peak();
+        if peak == T::ZERO {
+            return T::ONE;
+        }
+
+        let gain = target_peak / peak;
+        for ch in self.as_mut_slices() {
+            apply_gain_slice(ch, gain);
+        }
+
+        gain
+    }
+
+    /// Multiply frame `i` of every channel in this buffer by `env[i]`, such as when
+    /// applying an ADSR or LFO envelope computed at one value per frame.
+    ///
+    /// Only the first `frames().min(env.len())` frames will be processed.
+    pub fn apply_gain_envelope(&mut self, env: &[T])
+    where
+        T: MulAssign,
+    {
+        let frames = self.frames.min(env.len());
+        let mut channels = self.as_mut_slices_with_length(frames);
+
+        for (frame_i, &gain) in env[..frames].iter().enumerate() {
+            for ch in channels.iter_mut() {
+                ch[frame_i] *= gain;
+            }
+        }
+    }
+
+    /// Add triangular-PDF (TPDF) dither noise to every sample in every channel, as the
+    /// last step before reducing the bit depth (e.g. with the `convert_into` family).
+    ///
+    /// `rng` must return uniformly distributed values in the range `[0, 1)`. It is
+    /// called twice per sample, and the difference of the two values scaled by
+    /// `amplitude` is added to the sample, giving noise in the range
+    /// `(-amplitude, amplitude)`.
+    ///
+    /// `amplitude` is usually the size of one least significant bit of the target
+    /// format, such as `1.0 / 32768.0` for 16-bit audio, which fully decorrelates the
+    /// quantization error from the signal.
+    #[cfg(feature = "float")]
+    pub fn apply_tpdf_dither(&mut self, amplitude: T, mut rng: impl FnMut() -> T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            tpdf_dither_slice(ch, amplitude, &mut rng);
+        }
+    }
+
+    /// Apply `tanh(x * drive) / tanh(drive)` soft-clipping saturation to every sample
+    /// in every channel.
+    ///
+    /// This is a smoother, more musical alternative to [`clamp`](Self::clamp). The
+    /// output is normalized so that a sample of `±1` stays at `±1`, and higher values
+    /// of `drive` give a harder knee. This does nothing if `drive <= 0`.
+    ///
+    /// See [`apply_soft_clip_fast`](Self::apply_soft_clip_fast) for a cheaper
+    /// approximation.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_slice(ch, drive);
+        }
+    }
+
+    /// A cheaper version of [`apply_soft_clip`](Self::apply_soft_clip) which uses a
+    /// rational approximation of `tanh` instead of calling `tanh` for every sample.
+    ///
+    /// The approximation differs from `tanh` by less than `0.025` and hard-limits at
+    /// `±3` before normalization, which makes it well suited for real-time use where the
+    /// exact curve does not matter. This does nothing if `drive <= 0`.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip_fast(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_fast_slice(ch, drive);
+        }
+    }
+
+    /// Replace every subnormal (denormal) sample in each channel with zero.
+    ///
+    /// Subnormal values can severely degrade performance on many CPUs, especially
+    /// in feedback loops such as filters and reverbs.
+    #[cfg(feature = "float")]
+    pub fn flush_denormals(&mut self)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            flush_denormals_slice(ch);
+        }
+    }
+
     #[inline(always)]
     pub fn as_ref<'a>(&'a self) -> VarChannelBufferRef<'a, T, MAX_CHANNELS> {
         // SAFETY:
@@ -408,6 +1354,91 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
             VarChannelBufferRefMut::from_raw(&mut self.data, self.offsets.clone(), self.frames)
         }
     }
+
+    /// Split the channels of this buffer into two mutable views at `mid`.
+    ///
+    /// The first view contains the channels `[0, mid)` and the second view contains the
+    /// channels `[mid, self.channels())`. Both views share the same underlying data but
+    /// never overlap.
+    ///
+    /// Returns `None` if `mid == 0` or `mid >= self.channels()`, since a buffer must
+    /// always have at least one channel.
+    pub fn split_channels_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Option<(
+        VarChannelBufferRefMut<'_, T, MAX_CHANNELS>,
+        VarChannelBufferRefMut<'_, T, MAX_CHANNELS>,
+    )> {
+        // SAFETY: The constructors ensure that each of the channels in `offsets` points
+        // to a region of `frames` samples in `data`, in ascending order.
+        unsafe { split_channels_at_mut(&mut self.data, &self.offsets, self.frames, mid) }
+    }
+
+    /// Get the channels in `channels` as immutable slices, with each channel windowed
+    /// to the frames in `frames`.
+    ///
+    /// Both ranges are clamped to the bounds of this buffer.
+    ///
+    /// Returns `None` if the clamped channel range is empty.
+    pub fn sub_view(
+        &self,
+        channels: Range<usize>,
+        frames: Range<usize>,
+    ) -> Option<ArrayVec<&[T], MAX_CHANNELS>> {
+        self.as_ref().sub_view(channels, frames)
+    }
+
+    /// Split the channels of this buffer into two immutable views at `mid`.
+    ///
+    /// The first view contains the channels `[0, mid)` and the second view contains the
+    /// channels `[mid, self.channels())`. Both views share the same underlying data.
+    ///
+    /// Returns `None` if `mid == 0` or `mid >= self.channels()`, since a buffer must
+    /// always have at least one channel.
+    pub fn split_channels_at(
+        &self,
+        mid: usize,
+    ) -> Option<(
+        VarChannelBufferRef<'_, T, MAX_CHANNELS>,
+        VarChannelBufferRef<'_, T, MAX_CHANNELS>,
+    )> {
+        self.as_ref().split_channels_at(mid)
+    }
+
+    /// Get an immutable reference to this buffer which only exposes the first
+    /// `channels` channels.
+    ///
+    /// This does not modify the buffer itself.
+    ///
+    /// Returns `None` if `channels > self.channels()`.
+    #[inline]
+    pub fn as_ref_with_channels<'a>(
+        &'a self,
+        channels: NonZeroUsize,
+    ) -> Option<VarChannelBufferRef<'a, T, MAX_CHANNELS>> {
+        if channels.get() > self.offsets.len() {
+            return None;
+        }
+
+        let offsets = self.offsets[..channels.get()]
+            .iter()
+            .map(|ptr| *ptr as *const T)
+            .collect();
+
+        // SAFETY:
+        // * The constructors have the same invariants as `VarChannelBufferRef`.
+        // * We have checked that `channels` is within bounds, so the new offsets are a
+        // non-empty subset of the existing offsets, and they all point into the first
+        // `channels * frames` samples of the data.
+        unsafe {
+            Some(VarChannelBufferRef::from_raw(
+                &self.data[..channels.get() * self.frames],
+                offsets,
+                self.frames,
+            ))
+        }
+    }
 }
 
 impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Index<usize>
@@ -430,6 +1461,42 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Index
     }
 }
 
+impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Index<(usize, usize)>
+    for VarChannelBuffer<T, MAX_CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
+impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> IndexMut<(usize, usize)>
+    for VarChannelBuffer<T, MAX_CHANNELS>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (ch_i, frame_i): (usize, usize)) -> &mut Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // sample can exist.
+        unsafe { &mut *self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Default
     for VarChannelBuffer<T, MAX_CHANNELS>
 {
@@ -438,6 +1505,7 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Defau
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     Into<VarChannelBufferRef<'a, T, MAX_CHANNELS>> for &'a VarChannelBuffer<T, MAX_CHANNELS>
 {
@@ -447,6 +1515,7 @@ impl<'a, T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     Into<VarChannelBufferRefMut<'a, T, MAX_CHANNELS>>
     for &'a mut VarChannelBuffer<T, MAX_CHANNELS>
@@ -457,6 +1526,7 @@ impl<'a, T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Into<Vec<T>>
     for VarChannelBuffer<T, MAX_CHANNELS>
 {
@@ -465,6 +1535,17 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize> Into<
     }
 }
 
+impl<T: Clone + Copy + Default + Sized + Unpin, const MAX_CHANNELS: usize>
+    TryFrom<(Vec<T>, NonZeroUsize)> for VarChannelBuffer<T, MAX_CHANNELS>
+{
+    type Error = ChannelCountError;
+
+    /// See [`VarChannelBuffer::try_from_vec`].
+    fn try_from((data, channels): (Vec<T>, NonZeroUsize)) -> Result<Self, Self::Error> {
+        Self::try_from_vec(data, channels)
+    }
+}
+
 impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Clone
     for VarChannelBuffer<T, CHANNELS>
 {
@@ -480,13 +1561,137 @@ impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Clone
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `Vec<T>`, so it requires `T: Send`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Send
     for VarChannelBuffer<T, CHANNELS>
+where
+    T: Send,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `Vec<T>`, so it requires `T: Sync`.
 unsafe impl<T: Clone + Copy + Default + Sized + Unpin, const CHANNELS: usize> Sync
     for VarChannelBuffer<T, CHANNELS>
+where
+    T: Sync,
 {
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+    #[test]
+    fn from_raw_parts_with_slack_data() {
+        let buf = VarChannelBuffer::<u32, 4>::from_raw_parts(
+            alloc::vec![1, 2, 3, 4, 5, 6, 7, 8],
+            THREE,
+            2,
+        );
+
+        assert_eq!(buf.raw(), &[1, 2, 3, 4, 5, 6]);
+
+        let cloned = buf.clone();
+        assert_eq!(cloned.channels(), THREE);
+        assert_eq!(cloned.raw(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn grow_channels_keeps_existing_data() {
+        let mut buf = VarChannelBuffer::<u32, 4>::from_raw_parts(
+            alloc::vec![1, 2, 3, 4],
+            NonZeroUsize::new(2).unwrap(),
+            2,
+        );
+
+        assert!(buf.grow_channels(THREE).is_ok());
+        assert_eq!(buf.channels(), THREE);
+        assert_eq!(
+            buf.as_slices().as_slice(),
+            &[&[1, 2][..], &[3, 4][..], &[0, 0][..]]
+        );
+
+        // Growing to fewer channels does nothing.
+        assert!(buf.grow_channels(NonZeroUsize::new(1).unwrap()).is_ok());
+        assert_eq!(buf.channels(), THREE);
+    }
+
+    #[test]
+    fn grow_channels_rejects_exceeding_max_channels() {
+        let mut buf =
+            VarChannelBuffer::<u32, 4>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], THREE, 2);
+
+        assert_eq!(
+            buf.grow_channels(NonZeroUsize::new(5).unwrap()),
+            Err(ChannelCountError::ExceedsMaxChannels {
+                channels: 5,
+                max_channels: 4
+            })
+        );
+        assert_eq!(buf.raw(), &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn resize_frames_with_anchor() {
+        let mut buf =
+            VarChannelBuffer::<u32, 4>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], THREE, 2);
+
+        buf.resize_frames_with(3, Anchor::End);
+        assert_eq!(buf.raw(), &[0, 1, 2, 0, 3, 4, 0, 5, 6]);
+
+        buf.resize_frames_with(1, Anchor::Start);
+        assert_eq!(buf.as_slices().as_slice(), &[&[0][..], &[0][..], &[0][..]]);
+        assert_eq!(buf.channels(), THREE);
+    }
+
+    #[test]
+    fn truncate_channels_then_clone() {
+        let mut buf =
+            VarChannelBuffer::<u32, 4>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], THREE, 2);
+        let ptr = buf.raw().as_ptr();
+
+        buf.truncate_channels(NonZeroUsize::new(2).unwrap());
+        assert_eq!(buf.raw().as_ptr(), ptr);
+        assert_eq!(buf.raw(), &[1, 2, 3, 4]);
+
+        let cloned = buf.clone();
+        assert_eq!(cloned.channels().get(), 2);
+        assert_eq!(cloned.raw(), &[1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn as_ref_with_channels_bounds_raw_data() {
+        let buf =
+            VarChannelBuffer::<u32, 4>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], THREE, 2);
+
+        let view = buf
+            .as_ref_with_channels(NonZeroUsize::new(2).unwrap())
+            .unwrap();
+        assert_eq!(view.channels().get(), 2);
+        assert_eq!(view.raw(), &[1, 2, 3, 4]);
+
+        assert!(buf
+            .as_ref_with_channels(NonZeroUsize::new(4).unwrap())
+            .is_none());
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytes_round_trip_limited_view() {
+        let src =
+            VarChannelBuffer::<u32, 4>::from_raw_parts(alloc::vec![1, 2, 3, 4, 5, 6], THREE, 2);
+        let view = src
+            .as_ref_with_channels(NonZeroUsize::new(2).unwrap())
+            .unwrap();
+        assert_eq!(view.as_bytes().len(), 4 * 4);
+
+        let mut dst = VarChannelBuffer::<u32, 4>::new(NonZeroUsize::new(2).unwrap(), 2);
+        assert_eq!(dst.copy_from_bytes(view.as_bytes()), 4 * 4);
+        assert_eq!(dst.raw(), &[1, 2, 3, 4]);
+    }
+}
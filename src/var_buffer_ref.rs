@@ -1,7 +1,24 @@
 use core::num::NonZeroUsize;
-use core::ops::{Index, IndexMut, Range};
+use core::ops::{Index, IndexMut, MulAssign, Range};
 
 use arrayvec::ArrayVec;
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+
+#[cfg(feature = "float")]
+use crate::dsp::{
+    apply_gain_slice, fade_in_slice, fade_out_slice, flush_denormals_slice, peak_slice,
+    soft_clip_fast_slice, soft_clip_slice, tpdf_dither_slice, Float,
+};
+#[cfg(feature = "prefetch")]
+use crate::prefetch::prefetch_read;
+use crate::util::{
+    check_range, clamp_slice, clamped_frames, deinterleave, first_frame_where, interleave,
+    last_frame_where, shift_slice_left,
+};
+#[cfg(feature = "alloc")]
+use crate::VarChannelBuffer;
+use crate::{BufferShape, ChannelBufferRef, ChannelBufferRefMut, ChannelCountError, RangeError};
 
 /// An immutable memory-efficient buffer of samples with a fixed runtime number of
 /// channels each with a fixed runtime number of frames (samples in a single channel
@@ -22,7 +39,11 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         assert!(MAX_CHANNELS > 0);
     };
 
+    /// The maximum number of channels this buffer type can hold.
+    pub const MAX_CHANNELS: usize = MAX_CHANNELS;
+
     #[inline(always)]
+    #[allow(clippy::let_unit_value)]
     pub(crate) unsafe fn from_raw(
         data: &'a [T],
         offsets: ArrayVec<*const T, MAX_CHANNELS>,
@@ -38,6 +59,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     }
 
     /// Create an empty [`VarChannelBufferRef`] with no data.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -56,6 +78,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     ///
     /// # Panics
     /// Panics if `channels.get() > MAX_CHANNELS`.
+    #[allow(clippy::let_unit_value)]
     pub fn new(data: &'a [T], channels: NonZeroUsize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -87,9 +110,28 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     /// The caller must uphold that:
     /// * `data.len() >= frames * self.channels().get()`
     /// * and `channels.get() <= MAX_CHANNELS`
+    ///
+    /// These requirements are checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_unchecked(data: &'a [T], frames: usize, channels: NonZeroUsize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        debug_assert!(
+            channels.get() <= MAX_CHANNELS,
+            "`channels` ({}) must not be greater than `MAX_CHANNELS` ({})",
+            channels.get(),
+            MAX_CHANNELS
+        );
+        debug_assert!(
+            frames
+                .checked_mul(channels.get())
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * channels` ({} * {})",
+            data.len(),
+            frames,
+            channels.get()
+        );
+
         let mut offsets = ArrayVec::new();
         for ch_i in 0..channels.get() {
             offsets.push_unchecked(data.as_ptr().add(ch_i * frames));
@@ -108,12 +150,157 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         unsafe { NonZeroUsize::new_unchecked(self.offsets.len()) }
     }
 
+    /// The maximum number of channels this buffer can hold (the `MAX_CHANNELS` const
+    /// generic parameter).
+    pub fn max_channels(&self) -> usize {
+        MAX_CHANNELS
+    }
+
     /// The number of frames (samples in a single channel of audio) that are allocated
     /// in this buffer.
     pub fn frames(&self) -> usize {
         self.frames
     }
 
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        first_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        last_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        first_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        last_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        let frames = self.frames.min(dst.len());
+        let dst = &mut dst[..frames];
+        dst.fill(T::ZERO);
+
+        for ch in self.as_slices_with_length(frames) {
+            for (d, &s) in dst.iter_mut().zip(ch) {
+                *d = *d + s * s;
+            }
+        }
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let channels = self.offsets.len();
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / channels);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * channels],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -129,6 +316,18 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -147,6 +346,21 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> ArrayVec<&[T], MAX_CHANNELS> {
@@ -168,6 +382,31 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get an iterator over all channels as immutable slices, from the first channel to
+    /// the last. Each slice will have a length of `self.frames()`.
+    ///
+    /// The iterator is double-ended, so `.rev()` can be used to iterate back-to-front.
+    #[inline]
+    pub fn channels_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        let frames = self.frames;
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        self.offsets
+            .iter()
+            .map(move |ptr| unsafe { core::slice::from_raw_parts(*ptr, frames) })
+    }
+
+    /// Get an iterator over all channels as immutable slices, from the last channel to
+    /// the first. Each slice will have a length of `self.frames()`.
+    #[inline]
+    pub fn channels_iter_rev(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        self.channels_iter().rev()
+    }
+
     /// Get all channels as immutable slices with the given length in frames.
     ///
     /// If `frames > self.frames()`, then each slice will have a length of `self.frames()`
@@ -202,7 +441,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> ArrayVec<&[T], MAX_CHANNELS> {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         let mut v = ArrayVec::new();
 
@@ -223,10 +462,133 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`VarChannelBufferRef::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<ArrayVec<&[T], MAX_CHANNELS>, RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
+    /// Get the channels in `channels` as immutable slices, with each channel windowed
+    /// to the frames in `frames`.
+    ///
+    /// Both ranges are clamped to the bounds of this buffer.
+    ///
+    /// Returns `None` if the clamped channel range is empty.
+    pub fn sub_view(
+        &self,
+        channels: Range<usize>,
+        frames: Range<usize>,
+    ) -> Option<ArrayVec<&'a [T], MAX_CHANNELS>> {
+        // SAFETY: The constructors ensure that each of the channels in `offsets` points
+        // to a region of `frames` samples in `data`, which is valid for `'a`.
+        unsafe { sub_view(&self.offsets, self.frames, channels, frames) }
+    }
+
+    /// Split the channels of this buffer into two immutable views at `mid`.
+    ///
+    /// The first view contains the channels `[0, mid)` and the second view contains the
+    /// channels `[mid, self.channels())`. Both views share the same underlying data.
+    ///
+    /// Returns `None` if `mid == 0` or `mid >= self.channels()`, since a buffer must
+    /// always have at least one channel.
+    pub fn split_channels_at(
+        &self,
+        mid: usize,
+    ) -> Option<(
+        VarChannelBufferRef<'a, T, MAX_CHANNELS>,
+        VarChannelBufferRef<'a, T, MAX_CHANNELS>,
+    )> {
+        // SAFETY: The constructors ensure that each of the channels in `offsets` points
+        // to a region of `frames` samples in `data`, one after the other.
+        unsafe { split_channels_at(self.data, &self.offsets, self.frames, mid) }
+    }
+
+    /// Get all channels as an array of immutable slices. Each slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// Returns `None` if `self.channels().get() != N`.
+    #[inline]
+    pub fn try_as_array<const N: usize>(&self) -> Option<[&[T]; N]> {
+        if self.offsets.len() != N {
+            return None;
+        }
+
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * We have checked that `N` is equal to the number of channels.
+        unsafe {
+            Some(core::array::from_fn(|ch_i| {
+                core::slice::from_raw_parts(*self.offsets.get_unchecked(ch_i), self.frames)
+            }))
+        }
+    }
+
+    /// Allocate a new [`VarChannelBuffer`] holding a copy of every channel of this
+    /// buffer.
+    #[cfg(feature = "alloc")]
+    pub fn to_channel_buffer(&self) -> VarChannelBuffer<T, MAX_CHANNELS>
+    where
+        T: Unpin,
+    {
+        let mut buf = VarChannelBuffer::new(self.channels(), self.frames);
+        for (dst_ch, src_ch) in buf.as_mut_slices().into_iter().zip(self.as_slices()) {
+            dst_ch.copy_from_slice(src_ch);
+        }
+
+        buf
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
     pub fn raw(&self) -> &[T] {
         self.data
     }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
 }
 
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Index<usize>
@@ -240,6 +602,24 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Index<usi
     }
 }
 
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Index<(usize, usize)>
+    for VarChannelBufferRef<'a, T, MAX_CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Default
     for VarChannelBufferRef<'a, T, MAX_CHANNELS>
 {
@@ -248,6 +628,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Default
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Into<&'a [T]>
     for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
 {
@@ -258,14 +639,22 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Into<&'a
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// This type only gives shared access to the samples, like `&[T]`, so sending it
+// to another thread requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
     for VarChannelBufferRef<'a, T, CHANNELS>
+where
+    T: Sync,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&[T]`, so it requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
     for VarChannelBufferRef<'a, T, CHANNELS>
+where
+    T: Sync,
 {
 }
 
@@ -289,7 +678,11 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         assert!(MAX_CHANNELS > 0);
     };
 
+    /// The maximum number of channels this buffer type can hold.
+    pub const MAX_CHANNELS: usize = MAX_CHANNELS;
+
     #[inline(always)]
+    #[allow(clippy::let_unit_value)]
     pub(crate) unsafe fn from_raw(
         data: &'a mut [T],
         offsets: ArrayVec<*mut T, MAX_CHANNELS>,
@@ -305,6 +698,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     }
 
     /// Create an empty [`VarChannelBufferRefMut`] with no data.
+    #[allow(clippy::let_unit_value)]
     pub fn empty() -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -323,6 +717,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     ///
     /// # Panics
     /// Panics if `channels.get() > MAX_CHANNELS`.
+    #[allow(clippy::let_unit_value)]
     pub fn new(data: &'a mut [T], channels: NonZeroUsize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
@@ -354,9 +749,28 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     /// The caller must uphold that:
     /// * `data.len() >= frames * self.channels().get()`
     /// * and `channels.get() <= MAX_CHANNELS`
+    ///
+    /// These requirements are checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
     pub unsafe fn new_unchecked(data: &'a mut [T], frames: usize, channels: NonZeroUsize) -> Self {
         let _ = Self::_COMPILE_TIME_ASSERTS;
 
+        debug_assert!(
+            channels.get() <= MAX_CHANNELS,
+            "`channels` ({}) must not be greater than `MAX_CHANNELS` ({})",
+            channels.get(),
+            MAX_CHANNELS
+        );
+        debug_assert!(
+            frames
+                .checked_mul(channels.get())
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * channels` ({} * {})",
+            data.len(),
+            frames,
+            channels.get()
+        );
+
         let mut offsets = ArrayVec::new();
         for ch_i in 0..channels.get() {
             offsets.push_unchecked(data.as_mut_ptr().add(ch_i * frames));
@@ -375,12 +789,177 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         unsafe { NonZeroUsize::new_unchecked(self.offsets.len()) }
     }
 
+    /// The maximum number of channels this buffer can hold (the `MAX_CHANNELS` const
+    /// generic parameter).
+    pub fn max_channels(&self) -> usize {
+        MAX_CHANNELS
+    }
+
     /// The number of frames (samples in a single channel of audio) that are allocated
     /// in this buffer.
     pub fn frames(&self) -> usize {
         self.frames
     }
 
+    /// The shape of this buffer (the number of channels and the number of frames).
+    pub fn shape(&self) -> BufferShape {
+        BufferShape {
+            channels: self.offsets.len(),
+            frames: self.frames,
+        }
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get the index of the first frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn first_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        first_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the last frame in which the sample in any channel is not
+    /// equal to the default value (i.e. silence).
+    ///
+    /// Returns `None` if every sample is equal to the default value.
+    pub fn last_nonzero_frame(&self) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        last_frame_where(self.as_slices(), |s| *s != T::default())
+    }
+
+    /// Get the index of the first frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn first_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        first_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Get the index of the last frame in which the absolute value of the sample in
+    /// any channel is greater than `threshold`.
+    ///
+    /// Returns `None` if no sample is above the threshold.
+    #[cfg(feature = "float")]
+    pub fn last_frame_above(&self, threshold: T) -> Option<usize>
+    where
+        T: Float,
+    {
+        last_frame_where(self.as_slices(), |s| s.abs() > threshold)
+    }
+
+    /// Write the energy of each frame, summed across every channel, into `dst`, such
+    /// that `dst[f] = Σ_ch self[ch][f]²`. This is the basis of onset detectors and
+    /// gates.
+    ///
+    /// Rather than gathering the samples of every channel one frame at a time, this
+    /// accumulates one channel at a time into `dst`, so that each channel is read
+    /// contiguously.
+    ///
+    /// Only the first `self.frames().min(dst.len())` frames will be processed.
+    #[cfg(feature = "float")]
+    pub fn frame_energy_into(&self, dst: &mut [T])
+    where
+        T: Float,
+    {
+        let frames = self.frames.min(dst.len());
+        let dst = &mut dst[..frames];
+        dst.fill(T::ZERO);
+
+        for ch in self.as_slices_with_length(frames) {
+            for (d, &s) in dst.iter_mut().zip(ch) {
+                *d = *d + s * s;
+            }
+        }
+    }
+
+    /// Get the largest absolute value of any sample in any channel.
+    ///
+    /// Returns `0` if this buffer is empty.
+    #[cfg(feature = "float")]
+    pub fn peak(&self) -> T
+    where
+        T: Float,
+    {
+        self.as_slices().into_iter().fold(T::ZERO, |peak, ch| {
+            let ch_peak = peak_slice(ch);
+            if ch_peak > peak {
+                ch_peak
+            } else {
+                peak
+            }
+        })
+    }
+
+    /// Copy the interleaved samples in `interleaved` into the frames of this buffer
+    /// starting at `dst_start_frame`.
+    ///
+    /// This is useful for reading from an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in this buffer will be copied, and any
+    /// trailing partial frame in `interleaved` will be ignored. Returns the number of
+    /// frames that were copied.
+    pub fn deinterleave_into_at(&mut self, interleaved: &[T], dst_start_frame: usize) -> usize {
+        let channels = self.offsets.len();
+        let start = dst_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(interleaved.len() / channels);
+
+        deinterleave(
+            &interleaved[..frames * channels],
+            &mut self.as_mut_slices_with_range(start..start + frames),
+        );
+
+        frames
+    }
+
+    /// Copy the frames of this buffer starting at `src_start_frame` into `dst` as
+    /// interleaved samples.
+    ///
+    /// This is useful for writing into an interleaved ring buffer at an arbitrary
+    /// position. Only the frames that fit in both this buffer and `dst` will be copied.
+    /// Returns the number of frames that were copied.
+    pub fn interleave_from_at(&self, src_start_frame: usize, dst: &mut [T]) -> usize {
+        let channels = self.offsets.len();
+        let start = src_start_frame.min(self.frames);
+        let frames = (self.frames - start).min(dst.len() / channels);
+
+        interleave(
+            &self.as_slices_with_range(start..start + frames),
+            &mut dst[..frames * channels],
+        );
+
+        frames
+    }
+
+    /// Copy the samples of every channel at the given `frame` into `dst`, interleaved
+    /// in channel order.
+    ///
+    /// Returns `false` if `frame` is out of bounds or if `dst` has a length less than
+    /// `self.channels()`.
+    pub fn copy_frame_interleaved_into(&self, frame: usize, dst: &mut [T]) -> bool {
+        if frame >= self.frames || dst.len() < self.offsets.len() {
+            return false;
+        }
+
+        for (d, ch) in dst.iter_mut().zip(self.as_slices()) {
+            *d = ch[frame];
+        }
+
+        true
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -396,6 +975,18 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Get an immutable reference to the given range of frames in the channel at
+    /// `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range(&self, index: usize, range: Range<usize>) -> Option<&[T]> {
+        let ch = self.channel(index)?;
+        Some(&ch[clamped_frames(range, ch.len())])
+    }
+
     #[inline(always)]
     /// Get an immutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -414,6 +1005,21 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         core::slice::from_raw_parts(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get an immutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRef`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer(&self, index: usize) -> Option<ChannelBufferRef<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel(index)?;
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRef::from_raw(ch, [ch.as_ptr()], frames)) }
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -429,6 +1035,18 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         }
     }
 
+    /// Get a mutable reference to the given range of frames in the channel at `index`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be returned.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn channel_range_mut(&mut self, index: usize, range: Range<usize>) -> Option<&mut [T]> {
+        let ch = self.channel_mut(index)?;
+        let range = clamped_frames(range, ch.len());
+        Some(&mut ch[range])
+    }
+
     #[inline(always)]
     /// Get a mutable reference to the channel at `index`. The slice will have a length
     /// of `self.frames()`.
@@ -449,6 +1067,54 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         core::slice::from_raw_parts_mut(*self.offsets.get_unchecked(index), self.frames)
     }
 
+    /// Get a mutable reference to the channel at `index` as a mono buffer.
+    ///
+    /// This allows passing a single channel to functions which expect a
+    /// [`ChannelBufferRefMut`] without copying.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn channel_as_buffer_mut(&mut self, index: usize) -> Option<ChannelBufferRefMut<'_, T, 1>> {
+        let frames = self.frames;
+        let ch = self.channel_mut(index)?;
+        let ptr = ch.as_mut_ptr();
+
+        // SAFETY: The channel slice has a length of exactly `frames`.
+        unsafe { Some(ChannelBufferRefMut::from_raw(ch, [ptr], frames)) }
+    }
+
+    /// Get mutable references to the channels at each of the given `indices` at once.
+    /// Each slice will have a length of `self.frames()`.
+    ///
+    /// Returns `None` if any index is out of bounds or if any index appears more than
+    /// once.
+    #[inline]
+    pub fn channels_disjoint_mut<const N: usize>(
+        &mut self,
+        indices: [usize; N],
+    ) -> Option<[&mut [T]; N]> {
+        for (i, &index) in indices.iter().enumerate() {
+            if index >= self.offsets.len() || indices[..i].contains(&index) {
+                return None;
+            }
+        }
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * We have checked above that every index is within bounds and that no index
+        // appears more than once, so none of the returned slices overlap.
+        // * `self` is borrowed as mutable, so all mutability rules are being upheld.
+        unsafe {
+            Some(core::array::from_fn(|i| {
+                core::slice::from_raw_parts_mut(
+                    *self.offsets.get_unchecked(indices[i]),
+                    self.frames,
+                )
+            }))
+        }
+    }
+
     /// Get all channels as immutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_slices(&self) -> ArrayVec<&[T], MAX_CHANNELS> {
@@ -470,6 +1136,31 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get an iterator over all channels as immutable slices, from the first channel to
+    /// the last. Each slice will have a length of `self.frames()`.
+    ///
+    /// The iterator is double-ended, so `.rev()` can be used to iterate back-to-front.
+    #[inline]
+    pub fn channels_iter(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator + '_ {
+        let frames = self.frames;
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        self.offsets
+            .iter()
+            .map(move |ptr| unsafe { core::slice::from_raw_parts(*ptr, frames) })
+    }
+
+    /// Get an iterator over all channels as immutable slices, from the last channel to
+    /// the first. Each slice will have a length of `self.frames()`.
+    #[inline]
+    pub fn channels_iter_rev(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        self.channels_iter().rev()
+    }
+
     /// Get all channels as mutable slices. Each slice will have a length of `self.frames()`.
     #[inline]
     pub fn as_mut_slices(&mut self) -> ArrayVec<&mut [T], MAX_CHANNELS> {
@@ -493,6 +1184,35 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get an iterator over all channels as mutable slices, from the first channel to
+    /// the last. Each slice will have a length of `self.frames()`.
+    ///
+    /// The iterator is double-ended, so `.rev()` can be used to iterate back-to-front.
+    #[inline]
+    pub fn channels_iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator + '_ {
+        let frames = self.frames;
+
+        // SAFETY:
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`, and none of the channels overlap.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * `self` is borrowed as mutable for the lifetime of the iterator, so all
+        // mutability rules are being upheld.
+        self.offsets
+            .iter()
+            .map(move |ptr| unsafe { core::slice::from_raw_parts_mut(*ptr, frames) })
+    }
+
+    /// Get an iterator over all channels as mutable slices, from the last channel to
+    /// the first. Each slice will have a length of `self.frames()`.
+    #[inline]
+    pub fn channels_iter_rev_mut(&mut self) -> impl ExactSizeIterator<Item = &mut [T]> + '_ {
+        self.channels_iter_mut().rev()
+    }
+
     /// Get all channels as immutable slices with the given length in frames.
     ///
     /// If `frames > self.frames()`, then each slice will have a length of `self.frames()`
@@ -556,7 +1276,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     #[inline]
     pub fn as_slices_with_range(&self, range: Range<usize>) -> ArrayVec<&[T], MAX_CHANNELS> {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         let mut v = ArrayVec::new();
 
@@ -577,6 +1297,19 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get all channels as immutable slices in the given range.
+    ///
+    /// Unlike [`VarChannelBufferRefMut::as_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_slices_with_range(
+        &self,
+        range: Range<usize>,
+    ) -> Result<ArrayVec<&[T], MAX_CHANNELS>, RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_slices_with_range(range))
+    }
+
     /// Get all channels as immutable slices in the given range.
     ///
     /// If all or part of the range falls out of bounds, then only the part that falls
@@ -587,7 +1320,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         range: Range<usize>,
     ) -> ArrayVec<&mut [T], MAX_CHANNELS> {
         let start_frame = range.start.min(self.frames);
-        let frames = range.end.min(self.frames) - start_frame;
+        let frames = range.end.min(self.frames).saturating_sub(start_frame);
 
         let mut v = ArrayVec::new();
 
@@ -613,16 +1346,208 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
         v
     }
 
+    /// Get all channels as mutable slices in the given range.
+    ///
+    /// Unlike [`VarChannelBufferRefMut::as_mut_slices_with_range`], this returns an error if the
+    /// range is invalid or if any part of it falls out of bounds.
+    #[inline]
+    pub fn try_as_mut_slices_with_range(
+        &mut self,
+        range: Range<usize>,
+    ) -> Result<ArrayVec<&mut [T], MAX_CHANNELS>, RangeError> {
+        check_range(&range, self.frames)?;
+        Ok(self.as_mut_slices_with_range(range))
+    }
+
+    /// Get the channels in `channels` as immutable slices, with each channel windowed
+    /// to the frames in `frames`.
+    ///
+    /// Both ranges are clamped to the bounds of this buffer.
+    ///
+    /// Returns `None` if the clamped channel range is empty.
+    pub fn sub_view(
+        &self,
+        channels: Range<usize>,
+        frames: Range<usize>,
+    ) -> Option<ArrayVec<&[T], MAX_CHANNELS>> {
+        // SAFETY:
+        // * The constructors ensure that each of the channels in `offsets` points to a
+        // region of `frames` samples in `data`, which is borrowed for the lifetime of
+        // the returned slices.
+        // * `*const T` and `*mut T` are interchangeable bit-for-bit.
+        unsafe {
+            let offsets = &*(self.offsets.as_slice() as *const [*mut T] as *const [*const T]);
+            sub_view(offsets, self.frames, channels, frames)
+        }
+    }
+
+    /// Split the channels of this buffer into two immutable views at `mid`.
+    ///
+    /// The first view contains the channels `[0, mid)` and the second view contains the
+    /// channels `[mid, self.channels())`. Both views share the same underlying data.
+    ///
+    /// Returns `None` if `mid == 0` or `mid >= self.channels()`, since a buffer must
+    /// always have at least one channel.
+    pub fn split_channels_at(
+        &self,
+        mid: usize,
+    ) -> Option<(
+        VarChannelBufferRef<'_, T, MAX_CHANNELS>,
+        VarChannelBufferRef<'_, T, MAX_CHANNELS>,
+    )> {
+        // SAFETY:
+        // * The constructors ensure that each of the channels in `offsets` points to a
+        // region of `frames` samples in `data`, one after the other.
+        // * `*const T` and `*mut T` are interchangeable bit-for-bit.
+        unsafe {
+            let offsets = &*(self.offsets.as_slice() as *const [*mut T] as *const [*const T]);
+            split_channels_at(self.data, offsets, self.frames, mid)
+        }
+    }
+
+    /// Get all channels as an array of immutable slices. Each slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// Returns `None` if `self.channels().get() != N`.
+    #[inline]
+    pub fn try_as_array<const N: usize>(&self) -> Option<[&[T]; N]> {
+        if self.offsets.len() != N {
+            return None;
+        }
+
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * We have checked that `N` is equal to the number of channels.
+        unsafe {
+            Some(core::array::from_fn(|ch_i| {
+                core::slice::from_raw_parts(*self.offsets.get_unchecked(ch_i), self.frames)
+            }))
+        }
+    }
+
+    /// Split the channels of this buffer into two mutable views at `mid`.
+    ///
+    /// The first view contains the channels `[0, mid)` and the second view contains the
+    /// channels `[mid, self.channels())`. Both views share the same underlying data but
+    /// never overlap.
+    ///
+    /// Returns `None` if `mid == 0` or `mid >= self.channels()`, since a buffer must
+    /// always have at least one channel.
+    pub fn split_channels_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> Option<(
+        VarChannelBufferRefMut<'_, T, MAX_CHANNELS>,
+        VarChannelBufferRefMut<'_, T, MAX_CHANNELS>,
+    )> {
+        // SAFETY: The constructors ensure that each of the channels in `offsets` points
+        // to a region of `frames` samples in `data`, in ascending order.
+        unsafe { split_channels_at_mut(self.data, &self.offsets, self.frames, mid) }
+    }
+
+    /// Get all channels as an array of mutable slices. Each slice will have a length
+    /// of `self.frames()`.
+    ///
+    /// Returns `None` if `self.channels().get() != N`.
+    #[inline]
+    pub fn try_as_array_mut<const N: usize>(&mut self) -> Option<[&mut [T]; N]> {
+        if self.offsets.len() != N {
+            return None;
+        }
+
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `frames * self.channels()`.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * We have checked that `N` is equal to the number of channels.
+        // * `self` is borrowed as mutable, and none of these slices overlap, so all
+        // mutability rules are being upheld.
+        unsafe {
+            Some(core::array::from_fn(|ch_i| {
+                core::slice::from_raw_parts_mut(*self.offsets.get_unchecked(ch_i), self.frames)
+            }))
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of the channel at `index`.
+    ///
+    /// This can reduce cache misses when processing many channels of a large buffer.
+    /// This does nothing if `index` is out of bounds, on architectures other than
+    /// `x86`, `x86_64`, and `aarch64`, or on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_channel(&self, index: usize) {
+        if let Some(ptr) = self.offsets.get(index) {
+            prefetch_read(*ptr);
+        }
+    }
+
+    /// Issue a software prefetch hint for the start of every channel.
+    ///
+    /// This does nothing on architectures other than `x86`, `x86_64`, and `aarch64`, or
+    /// on `x86` without the `sse` target feature.
+    #[cfg(feature = "prefetch")]
+    #[inline]
+    pub fn prefetch_all(&self) {
+        for ptr in self.offsets.iter() {
+            prefetch_read(*ptr);
+        }
+    }
+
     /// Get the entire contents of the buffer as a single immutable slice.
     pub fn raw(&self) -> &[T] {
         self.data
     }
 
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.frames;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+
     /// Get the entire contents of the buffer as a single mutable slice.
     pub fn raw_mut(&mut self) -> &mut [T] {
         &mut self.data[..]
     }
 
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.frames;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        let dst = self.as_bytes_mut();
+        let len = dst.len().min(bytes.len());
+        dst[..len].copy_from_slice(&bytes[..len]);
+        len
+    }
+
     /// Clear all data with the default value.
     pub fn clear(&mut self) {
         self.raw_mut().fill(T::default());
@@ -634,6 +1559,274 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
             ch.fill(T::default());
         }
     }
+
+    /// Clear all data in the channel at `index` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel(&mut self, index: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        ch.fill(T::default());
+        true
+    }
+
+    /// Clear all data in the channel at `index` up to `frames` with the default value.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn clear_channel_frames(&mut self, index: usize, frames: usize) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        let frames = frames.min(ch.len());
+        ch[..frames].fill(T::default());
+        true
+    }
+
+    /// Move the samples in every channel left by `n` frames, discarding the first `n`
+    /// frames.
+    ///
+    /// If `clear` is `true`, then the last `n` frames will be filled with the default
+    /// value. Otherwise their contents are left unchanged (stale).
+    ///
+    /// Returns the number of frames at the start of the buffer that still contain
+    /// valid (shifted) data.
+    pub fn shift_frames_left(&mut self, n: usize, clear: bool) -> usize {
+        for ch in self.as_mut_slices() {
+            shift_slice_left(ch, n, clear);
+        }
+
+        self.frames - n.min(self.frames)
+    }
+
+    /// Copy each slice in `planes` into the corresponding channel of this buffer.
+    ///
+    /// Only the frames that overlap in both the plane and the channel will be copied.
+    ///
+    /// Returns an error if `planes.len() != self.channels().get()`.
+    pub fn copy_from_planes(&mut self, planes: &[&[T]]) -> Result<(), ChannelCountError> {
+        if planes.len() != self.offsets.len() {
+            return Err(ChannelCountError::Mismatch {
+                expected: self.offsets.len(),
+                actual: planes.len(),
+            });
+        }
+
+        for (ch, plane) in self.as_mut_slices().into_iter().zip(planes.iter()) {
+            let frames = ch.len().min(plane.len());
+            ch[..frames].copy_from_slice(&plane[..frames]);
+        }
+
+        Ok(())
+    }
+
+    /// Clamp every sample in each channel to the range `[min, max]`.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp(&mut self, min: T, max: T)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices() {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Clamp every sample in each channel within the given range of frames to the
+    /// range `[min, max]`.
+    ///
+    /// If all or part of the range falls out of bounds, then only the part that falls
+    /// within range will be clamped.
+    ///
+    /// # Panics
+    /// Panics if `min > max`, or if either `min` or `max` cannot be compared (i.e. NaN).
+    pub fn clamp_range(&mut self, min: T, max: T, range: Range<usize>)
+    where
+        T: PartialOrd,
+    {
+        assert!(min <= max);
+
+        for ch in self.as_mut_slices_with_range(range) {
+            clamp_slice(ch, min, max);
+        }
+    }
+
+    /// Apply a linear fade in to the first `frames` frames of every channel, ramping
+    /// from a gain of `0` at the first frame up to `1` at the last faded frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_in(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let frames = frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_in_slice(&mut ch[..frames]);
+        }
+    }
+
+    /// Apply a linear fade out to the last `frames` frames of every channel, ramping
+    /// from a gain of `1` at the first faded frame down to `0` at the last frame.
+    ///
+    /// `frames` is clamped to the number of frames in this buffer.
+    #[cfg(feature = "float")]
+    pub fn fade_out(&mut self, frames: usize)
+    where
+        T: Float,
+    {
+        let start = self.frames - frames.min(self.frames);
+        for ch in self.as_mut_slices() {
+            fade_out_slice(&mut ch[start..]);
+        }
+    }
+
+    /// Scale every channel by a single gain so that the peak absolute value of any
+    /// sample in this buffer becomes `target_peak`, preserving the balance between
+    /// channels.
+    ///
+    /// Returns the gain that was applied. If every sample is silent, then this does
+    /// nothing and returns a gain of `1`.
+    #[cfg(feature = "float")]
+    pub fn normalize(&mut self, target_peak: T) -> T
+    where
+        T: Float,
+    {
+        let peak = self.peak();
+        if peak == T::ZERO {
+            return T::ONE;
+        }
+
+        let gain = target_peak / peak;
+        for ch in self.as_mut_slices() {
+            apply_gain_slice(ch, gain);
+        }
+
+        gain
+    }
+
+    /// Multiply frame `i` of every channel in this buffer by `env[i]`, such as when
+    /// applying an ADSR or LFO envelope computed at one value per frame.
+    ///
+    /// Only the first `frames().min(env.len())` frames will be processed.
+    pub fn apply_gain_envelope(&mut self, env: &[T])
+    where
+        T: MulAssign,
+    {
+        let frames = self.frames.min(env.len());
+        let mut channels = self.as_mut_slices_with_length(frames);
+
+        for (frame_i, &gain) in env[..frames].iter().enumerate() {
+            for ch in channels.iter_mut() {
+                ch[frame_i] *= gain;
+            }
+        }
+    }
+
+    /// Add triangular-PDF (TPDF) dither noise to every sample in every channel, as the
+    /// last step before reducing the bit depth (e.g. with the `convert_into` family).
+    ///
+    /// `rng` must return uniformly distributed values in the range `[0, 1)`. It is
+    /// called twice per sample, and the difference of the two values scaled by
+    /// `amplitude` is added to the sample, giving noise in the range
+    /// `(-amplitude, amplitude)`.
+    ///
+    /// `amplitude` is usually the size of one least significant bit of the target
+    /// format, such as `1.0 / 32768.0` for 16-bit audio, which fully decorrelates the
+    /// quantization error from the signal.
+    #[cfg(feature = "float")]
+    pub fn apply_tpdf_dither(&mut self, amplitude: T, mut rng: impl FnMut() -> T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            tpdf_dither_slice(ch, amplitude, &mut rng);
+        }
+    }
+
+    /// Apply `tanh(x * drive) / tanh(drive)` soft-clipping saturation to every sample
+    /// in every channel.
+    ///
+    /// This is a smoother, more musical alternative to [`clamp`](Self::clamp). The
+    /// output is normalized so that a sample of `±1` stays at `±1`, and higher values
+    /// of `drive` give a harder knee. This does nothing if `drive <= 0`.
+    ///
+    /// See [`apply_soft_clip_fast`](Self::apply_soft_clip_fast) for a cheaper
+    /// approximation.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_slice(ch, drive);
+        }
+    }
+
+    /// A cheaper version of [`apply_soft_clip`](Self::apply_soft_clip) which uses a
+    /// rational approximation of `tanh` instead of calling `tanh` for every sample.
+    ///
+    /// The approximation differs from `tanh` by less than `0.025` and hard-limits at
+    /// `±3` before normalization, which makes it well suited for real-time use where the
+    /// exact curve does not matter. This does nothing if `drive <= 0`.
+    #[cfg(feature = "float")]
+    pub fn apply_soft_clip_fast(&mut self, drive: T)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            soft_clip_fast_slice(ch, drive);
+        }
+    }
+
+    /// Replace every subnormal (denormal) sample in each channel with zero.
+    ///
+    /// Subnormal values can severely degrade performance on many CPUs, especially
+    /// in feedback loops such as filters and reverbs.
+    #[cfg(feature = "float")]
+    pub fn flush_denormals(&mut self)
+    where
+        T: Float,
+    {
+        for ch in self.as_mut_slices() {
+            flush_denormals_slice(ch);
+        }
+    }
+
+    /// Overwrite every sample in the buffer with the value returned by `f`.
+    ///
+    /// `f` is called with the arguments `(channel_index, frame_index)`. The previous
+    /// contents of the buffer are ignored.
+    pub fn fill_from_fn(&mut self, mut f: impl FnMut(usize, usize) -> T) {
+        for (ch_i, ch) in self.as_mut_slices().into_iter().enumerate() {
+            for (frame_i, s) in ch.iter_mut().enumerate() {
+                *s = f(ch_i, frame_i);
+            }
+        }
+    }
+
+    /// Overwrite every sample in the channel at `index` with the value returned by `f`.
+    ///
+    /// `f` is called with the frame index as its argument. The previous contents of
+    /// the channel are ignored.
+    ///
+    /// Returns `false` if `index` is out of bounds.
+    pub fn fill_channel_from_fn(&mut self, index: usize, mut f: impl FnMut(usize) -> T) -> bool {
+        let Some(ch) = self.channel_mut(index) else {
+            return false;
+        };
+
+        for (frame_i, s) in ch.iter_mut().enumerate() {
+            *s = f(frame_i);
+        }
+
+        true
+    }
 }
 
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Index<usize>
@@ -656,6 +1849,42 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> IndexMut<
     }
 }
 
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Index<(usize, usize)>
+    for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
+{
+    type Output = T;
+
+    #[inline(always)]
+    fn index(&self, (ch_i, frame_i): (usize, usize)) -> &Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        unsafe { &*self.offsets[ch_i].add(frame_i) }
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> IndexMut<(usize, usize)>
+    for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
+{
+    #[inline(always)]
+    fn index_mut(&mut self, (ch_i, frame_i): (usize, usize)) -> &mut Self::Output {
+        assert!(frame_i < self.frames);
+
+        // SAFETY:
+        // * The channel index is bounds-checked when indexing into `offsets`.
+        // * We have checked that the frame index is within bounds above.
+        // * The constructors ensure that every channel pointer points to at least
+        // `frames` valid samples.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // sample can exist.
+        unsafe { &mut *self.offsets[ch_i].add(frame_i) }
+    }
+}
+
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Default
     for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
 {
@@ -664,6 +1893,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Default
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     Into<VarChannelBufferRef<'a, T, MAX_CHANNELS>> for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
 {
@@ -679,6 +1909,7 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>
     }
 }
 
+#[allow(clippy::from_over_into)]
 impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Into<&'a mut [T]>
     for VarChannelBufferRefMut<'a, T, MAX_CHANNELS>
 {
@@ -689,13 +1920,185 @@ impl<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize> Into<&'a
 
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `&mut [T]`, so it requires `T: Send`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Send
     for VarChannelBufferRefMut<'a, T, CHANNELS>
+where
+    T: Send,
 {
 }
 // # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
 // the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&mut [T]`, so it requires `T: Sync`.
 unsafe impl<'a, T: Clone + Copy + Default + Sized, const CHANNELS: usize> Sync
     for VarChannelBufferRefMut<'a, T, CHANNELS>
+where
+    T: Sync,
 {
 }
+
+/// Split the given channels into two disjoint mutable views at `mid`.
+///
+/// Returns `None` if `mid == 0` or `mid >= offsets.len()`.
+///
+/// # Safety
+/// Each pointer in `offsets` must point to a region of `frames` samples in `data`,
+/// and the channels must be stored one after the other.
+pub(crate) unsafe fn split_channels_at_mut<
+    'a,
+    T: Clone + Copy + Default + Sized,
+    const MAX_CHANNELS: usize,
+>(
+    data: &'a mut [T],
+    offsets: &[*mut T],
+    frames: usize,
+    mid: usize,
+) -> Option<(
+    VarChannelBufferRefMut<'a, T, MAX_CHANNELS>,
+    VarChannelBufferRefMut<'a, T, MAX_CHANNELS>,
+)> {
+    if mid == 0 || mid >= offsets.len() {
+        return None;
+    }
+
+    // SAFETY: The caller upholds that the pointers point into `data`.
+    let start_i = offsets.get_unchecked(0).offset_from(data.as_ptr()) as usize;
+    let split_i = offsets.get_unchecked(mid).offset_from(data.as_ptr()) as usize;
+    let end_i = split_i + ((offsets.len() - mid) * frames);
+
+    let (data_a, data_b) = data[start_i..end_i].split_at_mut(split_i - start_i);
+
+    // SAFETY:
+    // * The caller upholds that the channels are stored one after the other, so all
+    // channels before `mid` lie in `data_a` and all channels from `mid` onwards lie in
+    // `data_b`.
+    // * We have checked that both halves contain at least one channel.
+    // * The two halves of the data slice do not overlap, so all mutability rules are
+    // being upheld.
+    Some((
+        VarChannelBufferRefMut::from_raw(data_a, offsets[..mid].iter().copied().collect(), frames),
+        VarChannelBufferRefMut::from_raw(data_b, offsets[mid..].iter().copied().collect(), frames),
+    ))
+}
+
+/// Get the channels in `channels` of a buffer as immutable slices, each windowed to
+/// the frames in `frame_range`. Both ranges are clamped to the bounds of the buffer.
+///
+/// Returns `None` if the clamped channel range is empty.
+///
+/// # Safety
+/// Every pointer in `offsets` must point to a region of `frames` samples which is
+/// valid for reads for the lifetime `'a`.
+pub(crate) unsafe fn sub_view<'a, T: Clone + Copy + Default + Sized, const MAX_CHANNELS: usize>(
+    offsets: &[*const T],
+    frames: usize,
+    channels: Range<usize>,
+    frame_range: Range<usize>,
+) -> Option<ArrayVec<&'a [T], MAX_CHANNELS>> {
+    let start_ch = channels.start.min(offsets.len());
+    let end_ch = channels.end.min(offsets.len());
+    if start_ch >= end_ch {
+        return None;
+    }
+
+    let start_frame = frame_range.start.min(frames);
+    let sub_frames = frame_range.end.min(frames).saturating_sub(start_frame);
+
+    // SAFETY:
+    // * The caller upholds that every pointer points to a region of `frames` samples,
+    // and we have constrained the frame range above.
+    // * We have constrained the channel range above, so there are at most
+    // `MAX_CHANNELS` slices.
+    unsafe {
+        Some(
+            offsets[start_ch..end_ch]
+                .iter()
+                .map(|ptr| core::slice::from_raw_parts(ptr.add(start_frame), sub_frames))
+                .collect(),
+        )
+    }
+}
+
+/// Split the given channels into two immutable views at `mid`.
+///
+/// Returns `None` if `mid == 0` or `mid >= offsets.len()`.
+///
+/// # Safety
+/// Each pointer in `offsets` must point to a region of `frames` samples in `data`,
+/// and the channels must be stored one after the other.
+pub(crate) unsafe fn split_channels_at<
+    'a,
+    T: Clone + Copy + Default + Sized,
+    const MAX_CHANNELS: usize,
+>(
+    data: &'a [T],
+    offsets: &[*const T],
+    frames: usize,
+    mid: usize,
+) -> Option<(
+    VarChannelBufferRef<'a, T, MAX_CHANNELS>,
+    VarChannelBufferRef<'a, T, MAX_CHANNELS>,
+)> {
+    if mid == 0 || mid >= offsets.len() {
+        return None;
+    }
+
+    // SAFETY: The caller upholds that the pointers point into `data`.
+    let start_i = offsets.get_unchecked(0).offset_from(data.as_ptr()) as usize;
+    let split_i = offsets.get_unchecked(mid).offset_from(data.as_ptr()) as usize;
+    let end_i = split_i + ((offsets.len() - mid) * frames);
+
+    // SAFETY:
+    // * The caller upholds that the channels are stored one after the other, so all
+    // channels before `mid` lie in `data[start_i..split_i]` and all channels from
+    // `mid` onwards lie in `data[split_i..end_i]`.
+    // * We have checked that both halves contain at least one channel.
+    Some((
+        VarChannelBufferRef::from_raw(
+            &data[start_i..split_i],
+            offsets[..mid].iter().copied().collect(),
+            frames,
+        ),
+        VarChannelBufferRef::from_raw(
+            &data[split_i..end_i],
+            offsets[mid..].iter().copied().collect(),
+            frames,
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THREE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+    #[test]
+    fn sub_view_only_holds_the_window() {
+        let data = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let buf = VarChannelBufferRef::<u32, 4>::new(&data, THREE);
+
+        let view = buf.sub_view(1..3, 1..3).unwrap();
+        assert_eq!(view.as_slice(), &[&[5, 6][..], &[8, 9][..]]);
+
+        assert!(buf.sub_view(3..4, 0..3).is_none());
+    }
+
+    #[test]
+    fn split_channels_at_bounds_raw_data() {
+        let mut data = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+        let buf = VarChannelBufferRef::<u32, 4>::new(&data, THREE);
+        let (a, b) = buf.split_channels_at(1).unwrap();
+        assert_eq!(a.raw(), &[1, 2, 3]);
+        assert_eq!(b.raw(), &[4, 5, 6, 7, 8, 9]);
+
+        let mut buf = VarChannelBufferRefMut::<u32, 4>::new(&mut data, THREE);
+        let (mut a, mut b) = buf.split_channels_at_mut(2).unwrap();
+        a.raw_mut().fill(0);
+        b.raw_mut().fill(1);
+        assert_eq!(data, [0, 0, 0, 0, 0, 0, 1, 1, 1]);
+    }
+}
@@ -0,0 +1,569 @@
+use core::num::NonZeroUsize;
+
+use arrayvec::ArrayVec;
+#[cfg(feature = "bytemuck")]
+use bytemuck::Pod;
+
+use crate::{ChannelBufferRef, ChannelBufferRefMut};
+
+/// An immutable memory-efficient buffer of samples with a fixed runtime number of
+/// instances each with a fixed compile-time number of `CHANNELS`. Each channel has a
+/// fixed runtime number of frames (samples in a single channel of audio).
+///
+/// This version uses a reference to a slice as its data source.
+#[derive(Debug, Clone)]
+pub struct VarInstanceChannelBufferRef<
+    'a,
+    T: Clone + Copy + Default + Sized,
+    const MAX_INSTANCES: usize,
+    const CHANNELS: usize,
+> {
+    data: &'a [T],
+    offsets: ArrayVec<[*const T; CHANNELS], MAX_INSTANCES>,
+    frames: usize,
+    instance_length: usize,
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS>
+{
+    const _COMPILE_TIME_ASSERTS: () = {
+        assert!(MAX_INSTANCES > 0);
+        assert!(CHANNELS > 0);
+    };
+
+    /// The maximum number of instances this buffer type can hold.
+    pub const MAX_INSTANCES: usize = MAX_INSTANCES;
+
+    /// Create an empty [`VarInstanceChannelBufferRef`] with no data.
+    #[allow(clippy::let_unit_value)]
+    pub fn empty() -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        let data = &[];
+        let mut offsets = ArrayVec::new();
+        offsets.push(core::array::from_fn(|_| data.as_ptr()));
+
+        Self {
+            data,
+            offsets,
+            frames: 0,
+            instance_length: 0,
+        }
+    }
+
+    /// Create a new [`VarInstanceChannelBufferRef`] using the given slice as the data.
+    ///
+    /// # Panics
+    /// Panics if `num_instances.get() > MAX_INSTANCES`.
+    #[allow(clippy::let_unit_value)]
+    pub fn new(data: &'a [T], num_instances: NonZeroUsize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        assert!(num_instances.get() <= MAX_INSTANCES);
+
+        let frames = data.len() / (num_instances.get() * CHANNELS);
+
+        // SAFETY:
+        // * `data.len() >= frames * CHANNELS * num_instances`.
+        // * We have constrained `num_instances` above.
+        unsafe { Self::new_unchecked(data, frames, num_instances) }
+    }
+
+    /// Create a new [`VarInstanceChannelBufferRef`] using the given slice as the data.
+    ///
+    /// # Safety
+    /// The caller must uphold that:
+    /// * `data.len() >= frames * CHANNELS * num_instances.get()`
+    /// * and `num_instances.get() <= MAX_INSTANCES`
+    ///
+    /// These requirements are checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
+    pub unsafe fn new_unchecked(data: &'a [T], frames: usize, num_instances: NonZeroUsize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        debug_assert!(
+            num_instances.get() <= MAX_INSTANCES,
+            "`num_instances` ({}) must not be greater than `MAX_INSTANCES` ({})",
+            num_instances.get(),
+            MAX_INSTANCES
+        );
+        debug_assert!(
+            frames
+                .checked_mul(CHANNELS)
+                .and_then(|n| n.checked_mul(num_instances.get()))
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * CHANNELS * num_instances` ({} * {} * {})",
+            data.len(),
+            frames,
+            CHANNELS,
+            num_instances.get()
+        );
+
+        let instance_length = frames * CHANNELS;
+
+        let mut offsets = ArrayVec::new();
+        for inst_i in 0..num_instances.get() {
+            offsets.push_unchecked(core::array::from_fn(|ch_i| {
+                data.as_ptr()
+                    .add((instance_length * inst_i) + (frames * ch_i))
+            }));
+        }
+
+        Self {
+            data,
+            offsets,
+            frames,
+            instance_length,
+        }
+    }
+
+    /// The number of instances in this buffer.
+    pub fn num_instances(&self) -> NonZeroUsize {
+        // SAFETY: The constructors ensure that there is at least one element in `offsets`.
+        unsafe { NonZeroUsize::new_unchecked(self.offsets.len()) }
+    }
+
+    /// The maximum number of instances this buffer can hold (the `MAX_INSTANCES` const
+    /// generic parameter).
+    pub fn max_instances(&self) -> usize {
+        MAX_INSTANCES
+    }
+
+    /// The number of channels in this buffer.
+    pub fn channels(&self) -> usize {
+        CHANNELS
+    }
+
+    /// The number of frames (samples in a single channel of audio) that are allocated
+    /// in this buffer.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get an immutable reference to the instance at the given index.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline(always)]
+    pub fn instance(&self, index: usize) -> Option<ChannelBufferRef<'a, T, CHANNELS>> {
+        if index < self.offsets.len() {
+            // # SAFETY:
+            // We have checked that `index` is within bounds.
+            unsafe { Some(self.instance_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the instance at the given index.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.num_instances()`.
+    #[inline(always)]
+    pub unsafe fn instance_unchecked(&self, index: usize) -> ChannelBufferRef<'a, T, CHANNELS> {
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `num_instances * frames * CHANNELS`.
+        // * The caller upholds that `index` is within bounds.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * We have asserted at compile-time that both `MAX_INSTANCES` and `CHANNELS` are
+        // non-zero.
+        ChannelBufferRef::from_raw(
+            core::slice::from_raw_parts(
+                *self.offsets.get_unchecked(index).get_unchecked(0),
+                self.instance_length,
+            ),
+            *self.offsets.get_unchecked(index),
+            self.frames,
+        )
+    }
+
+    /// Get the entire contents of the buffer as a single immutable slice.
+    pub fn raw(&self) -> &[T] {
+        self.data
+    }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.instance_length;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Default for VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS>
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// This type only gives shared access to the samples, like `&[T]`, so sending it
+// to another thread requires `T: Sync`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Send for VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS>
+where
+    T: Sync,
+{
+}
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&[T]`, so it requires `T: Sync`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Sync for VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS>
+where
+    T: Sync,
+{
+}
+
+/// A mutable memory-efficient buffer of samples with a fixed runtime number of
+/// instances each with a fixed compile-time number of `CHANNELS`. Each channel has a
+/// fixed runtime number of frames (samples in a single channel of audio).
+///
+/// This version uses a reference to a slice as its data source.
+#[derive(Debug)]
+pub struct VarInstanceChannelBufferRefMut<
+    'a,
+    T: Clone + Copy + Default + Sized,
+    const MAX_INSTANCES: usize,
+    const CHANNELS: usize,
+> {
+    data: &'a mut [T],
+    offsets: ArrayVec<[*mut T; CHANNELS], MAX_INSTANCES>,
+    frames: usize,
+    instance_length: usize,
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    VarInstanceChannelBufferRefMut<'a, T, MAX_INSTANCES, CHANNELS>
+{
+    const _COMPILE_TIME_ASSERTS: () = {
+        assert!(MAX_INSTANCES > 0);
+        assert!(CHANNELS > 0);
+    };
+
+    /// The maximum number of instances this buffer type can hold.
+    pub const MAX_INSTANCES: usize = MAX_INSTANCES;
+
+    /// Create an empty [`VarInstanceChannelBufferRefMut`] with no data.
+    #[allow(clippy::let_unit_value)]
+    pub fn empty() -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        let data = &mut [];
+        let mut offsets = ArrayVec::new();
+        offsets.push(core::array::from_fn(|_| data.as_mut_ptr()));
+
+        Self {
+            data,
+            offsets,
+            frames: 0,
+            instance_length: 0,
+        }
+    }
+
+    /// Create a new [`VarInstanceChannelBufferRefMut`] using the given slice as the data.
+    ///
+    /// # Panics
+    /// Panics if `num_instances.get() > MAX_INSTANCES`.
+    #[allow(clippy::let_unit_value)]
+    pub fn new(data: &'a mut [T], num_instances: NonZeroUsize) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        assert!(num_instances.get() <= MAX_INSTANCES);
+
+        let frames = data.len() / (num_instances.get() * CHANNELS);
+
+        // SAFETY:
+        // * `data.len() >= frames * CHANNELS * num_instances`.
+        // * We have constrained `num_instances` above.
+        unsafe { Self::new_unchecked(data, frames, num_instances) }
+    }
+
+    /// Create a new [`VarInstanceChannelBufferRefMut`] using the given slice as the data.
+    ///
+    /// # Safety
+    /// The caller must uphold that:
+    /// * `data.len() >= frames * CHANNELS * num_instances.get()`
+    /// * and `num_instances.get() <= MAX_INSTANCES`
+    ///
+    /// These requirements are checked with a panic in debug builds.
+    #[allow(clippy::let_unit_value)]
+    pub unsafe fn new_unchecked(
+        data: &'a mut [T],
+        frames: usize,
+        num_instances: NonZeroUsize,
+    ) -> Self {
+        let _ = Self::_COMPILE_TIME_ASSERTS;
+
+        debug_assert!(
+            num_instances.get() <= MAX_INSTANCES,
+            "`num_instances` ({}) must not be greater than `MAX_INSTANCES` ({})",
+            num_instances.get(),
+            MAX_INSTANCES
+        );
+        debug_assert!(
+            frames
+                .checked_mul(CHANNELS)
+                .and_then(|n| n.checked_mul(num_instances.get()))
+                .is_some_and(|n| data.len() >= n),
+            "`data.len()` ({}) must be at least `frames * CHANNELS * num_instances` ({} * {} * {})",
+            data.len(),
+            frames,
+            CHANNELS,
+            num_instances.get()
+        );
+
+        let instance_length = frames * CHANNELS;
+
+        let mut offsets = ArrayVec::new();
+        for inst_i in 0..num_instances.get() {
+            offsets.push_unchecked(core::array::from_fn(|ch_i| {
+                data.as_mut_ptr()
+                    .add((instance_length * inst_i) + (frames * ch_i))
+            }));
+        }
+
+        Self {
+            data,
+            offsets,
+            frames,
+            instance_length,
+        }
+    }
+
+    /// The number of instances in this buffer.
+    pub fn num_instances(&self) -> NonZeroUsize {
+        // SAFETY: The constructors ensure that there is at least one element in `offsets`.
+        unsafe { NonZeroUsize::new_unchecked(self.offsets.len()) }
+    }
+
+    /// The maximum number of instances this buffer can hold (the `MAX_INSTANCES` const
+    /// generic parameter).
+    pub fn max_instances(&self) -> usize {
+        MAX_INSTANCES
+    }
+
+    /// The number of channels in this buffer.
+    pub fn channels(&self) -> usize {
+        CHANNELS
+    }
+
+    /// The number of frames (samples in a single channel of audio) that are allocated
+    /// in this buffer.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Returns `true` if this buffer contains no frames (samples in a single channel
+    /// of audio).
+    pub fn is_empty(&self) -> bool {
+        self.frames == 0
+    }
+
+    /// Get an immutable reference to the instance at the given index.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline(always)]
+    pub fn instance<'b>(&'b self, index: usize) -> Option<ChannelBufferRef<'b, T, CHANNELS>> {
+        if index < self.offsets.len() {
+            // # SAFETY:
+            // We have checked that `index` is within bounds.
+            unsafe { Some(self.instance_unchecked(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the instance at the given index.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.num_instances()`.
+    #[inline(always)]
+    pub unsafe fn instance_unchecked<'b>(
+        &'b self,
+        index: usize,
+    ) -> ChannelBufferRef<'b, T, CHANNELS> {
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `num_instances * frames * CHANNELS`.
+        // * The caller upholds that `index` is within bounds.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * `[*const T; CHANNELS]` and `[*mut T; CHANNELS]` are interchangeable bit-for-bit.
+        // * We have asserted at compile-time that both `MAX_INSTANCES` and `CHANNELS` are
+        // non-zero.
+        ChannelBufferRef::from_raw(
+            core::slice::from_raw_parts(
+                *self.offsets.get_unchecked(index).get_unchecked(0),
+                self.instance_length,
+            ),
+            core::mem::transmute_copy(self.offsets.get_unchecked(index)),
+            self.frames,
+        )
+    }
+
+    /// Get a mutable reference to the instance at the given index.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline(always)]
+    pub fn instance_mut<'b>(
+        &'b mut self,
+        index: usize,
+    ) -> Option<ChannelBufferRefMut<'b, T, CHANNELS>> {
+        if index < self.offsets.len() {
+            // # SAFETY:
+            // We have checked that `index` is within bounds.
+            unsafe { Some(self.instance_unchecked_mut(index)) }
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the instance at the given index.
+    ///
+    /// # Safety
+    /// `index` must be less than `self.num_instances()`.
+    #[inline(always)]
+    pub unsafe fn instance_unchecked_mut<'b>(
+        &'b mut self,
+        index: usize,
+    ) -> ChannelBufferRefMut<'b, T, CHANNELS> {
+        // SAFETY:
+        //
+        // * The constructors ensure that the pointed-to data slice has a length of at
+        // least `num_instances * frames * CHANNELS`.
+        // * The caller upholds that `index` is within bounds.
+        // * The data slice cannot be moved, so the pointers are valid for the lifetime
+        // of the slice.
+        // * `self` is borrowed as mutable, ensuring that no other references to the
+        // data slice can exist.
+        // * We have asserted at compile-time that both `MAX_INSTANCES` and `CHANNELS` are
+        // non-zero.
+        ChannelBufferRefMut::from_raw(
+            core::slice::from_raw_parts_mut(
+                *self.offsets.get_unchecked(index).get_unchecked(0),
+                self.instance_length,
+            ),
+            *self.offsets.get_unchecked(index),
+            self.frames,
+        )
+    }
+
+    /// Get the entire contents of the buffer as a single immutable slice.
+    pub fn raw(&self) -> &[T] {
+        self.data
+    }
+
+    /// Get the samples of every channel in the buffer as a single immutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes(&self) -> &[u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.instance_length;
+        bytemuck::cast_slice(&self.raw()[..len])
+    }
+
+    /// Get the entire contents of the buffer as a single mutable slice.
+    pub fn raw_mut(&mut self) -> &mut [T] {
+        &mut self.data[..]
+    }
+
+    /// Get the samples of every channel in the buffer as a single mutable slice of
+    /// bytes.
+    #[cfg(feature = "bytemuck")]
+    pub fn as_bytes_mut(&mut self) -> &mut [u8]
+    where
+        T: Pod,
+    {
+        let len = self.offsets.len() * self.instance_length;
+        bytemuck::cast_slice_mut(&mut self.raw_mut()[..len])
+    }
+
+    /// Copy the given bytes into the samples of every channel in the buffer.
+    ///
+    /// `bytes` does not need to be aligned to `T`. Only the bytes that overlap with the
+    /// contents of the buffer will be copied. Returns the number of bytes that were
+    /// copied.
+    #[cfg(feature = "bytemuck")]
+    pub fn copy_from_bytes(&mut self, bytes: &[u8]) -> usize
+    where
+        T: Pod,
+    {
+        let dst = self.as_bytes_mut();
+        let len = dst.len().min(bytes.len());
+        dst[..len].copy_from_slice(&bytes[..len]);
+        len
+    }
+
+    /// Clear all data with the default value.
+    pub fn clear(&mut self) {
+        self.raw_mut().fill(T::default());
+    }
+}
+
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Default for VarInstanceChannelBufferRefMut<'a, T, MAX_INSTANCES, CHANNELS>
+{
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[allow(clippy::from_over_into)]
+impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Into<VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS>>
+    for VarInstanceChannelBufferRefMut<'a, T, MAX_INSTANCES, CHANNELS>
+{
+    #[inline(always)]
+    fn into(self) -> VarInstanceChannelBufferRef<'a, T, MAX_INSTANCES, CHANNELS> {
+        VarInstanceChannelBufferRef {
+            data: self.data,
+            // SAFETY: `ArrayVec<[*const T; CHANNELS], MAX_INSTANCES>` and
+            // `ArrayVec<[*mut T; CHANNELS], MAX_INSTANCES>` are interchangeable bit-for-bit.
+            offsets: unsafe { core::mem::transmute_copy(&self.offsets) },
+            frames: self.frames,
+            instance_length: self.instance_length,
+        }
+    }
+}
+
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sending this type to another thread moves access to the samples with it, like
+// `&mut [T]`, so it requires `T: Send`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Send for VarInstanceChannelBufferRefMut<'a, T, MAX_INSTANCES, CHANNELS>
+where
+    T: Send,
+{
+}
+// # SAFETY: All the stored pointers are valid for the lifetime of the struct, and
+// the public API prevents misuse of the pointers.
+// Sharing this type between threads only gives shared access to the samples, like
+// `&mut [T]`, so it requires `T: Sync`.
+unsafe impl<'a, T: Clone + Copy + Default + Sized, const MAX_INSTANCES: usize, const CHANNELS: usize>
+    Sync for VarInstanceChannelBufferRefMut<'a, T, MAX_INSTANCES, CHANNELS>
+where
+    T: Sync,
+{
+}
@@ -0,0 +1,148 @@
+//! Round-trip tests for the interleave and deinterleave methods.
+
+#![cfg(feature = "alloc")]
+
+use audio_channel_buffer::ChannelBuffer;
+
+const FRAME_COUNTS: [usize; 6] = [0, 1, 2, 3, 7, 31];
+
+/// A sample value which is unique to each channel and frame.
+fn sample(ch: usize, frame: usize) -> u32 {
+    (ch as u32) << 16 | frame as u32
+}
+
+fn filled<const CHANNELS: usize>(frames: usize) -> ChannelBuffer<u32, CHANNELS> {
+    let mut buf = ChannelBuffer::new(frames);
+    buf.as_mut().fill_from_fn(sample);
+    buf
+}
+
+fn interleaved(channels: usize, frames: usize) -> Vec<u32> {
+    (0..frames)
+        .flat_map(|frame| (0..channels).map(move |ch| sample(ch, frame)))
+        .collect()
+}
+
+fn round_trip<const CHANNELS: usize>() {
+    for frames in FRAME_COUNTS {
+        let src = filled::<CHANNELS>(frames);
+
+        // A destination which is not a multiple of the number of channels should only
+        // receive whole frames, leaving the trailing partial frame untouched.
+        for extra in [0, 1, CHANNELS - 1] {
+            let mut dst = vec![u32::MAX; frames * CHANNELS + extra];
+
+            assert_eq!(src.interleave_from_at(0, &mut dst), frames);
+            assert_eq!(dst[..frames * CHANNELS], interleaved(CHANNELS, frames));
+            assert!(dst[frames * CHANNELS..].iter().all(|&s| s == u32::MAX));
+
+            let mut back = ChannelBuffer::<u32, CHANNELS>::new(frames);
+            assert_eq!(back.deinterleave_into_at(&dst, 0), frames);
+            assert_eq!(back.as_slices(), src.as_slices());
+        }
+    }
+}
+
+fn round_trip_at<const CHANNELS: usize>() {
+    for frames in FRAME_COUNTS {
+        let src = filled::<CHANNELS>(frames);
+
+        for start in [0, 1, frames / 2, frames, frames + 1] {
+            let copied = frames.saturating_sub(start);
+
+            let mut dst = vec![u32::MAX; frames * CHANNELS];
+            assert_eq!(src.interleave_from_at(start, &mut dst), copied);
+
+            let mut back = ChannelBuffer::<u32, CHANNELS>::new(frames);
+            assert_eq!(
+                back.deinterleave_into_at(&dst[..copied * CHANNELS], start),
+                copied
+            );
+
+            let start = start.min(frames);
+            for (back_ch, src_ch) in back.as_slices().into_iter().zip(src.as_slices()) {
+                assert!(back_ch[..start].iter().all(|&s| s == 0));
+                assert_eq!(back_ch[start..], src_ch[start..]);
+            }
+        }
+    }
+}
+
+fn constructors<const CHANNELS: usize>() {
+    for frames in FRAME_COUNTS {
+        let expected = filled::<CHANNELS>(frames);
+
+        // Any trailing partial frame is discarded by every constructor.
+        for extra in [0, CHANNELS - 1] {
+            let mut data = interleaved(CHANNELS, frames);
+            data.extend((0..extra).map(|_| u32::MAX));
+
+            let buf = ChannelBuffer::<u32, CHANNELS>::from_interleaved_iter(data.clone());
+            assert_eq!(buf.as_slices(), expected.as_slices());
+
+            let buf = ChannelBuffer::<u32, CHANNELS>::from_interleaved_iter_with_frames(
+                data.clone(),
+                frames,
+            );
+            assert_eq!(buf.as_slices(), expected.as_slices());
+
+            let buf = ChannelBuffer::<u32, CHANNELS>::from_interleaved_vec_in_place(data);
+            assert_eq!(buf.as_slices(), expected.as_slices());
+        }
+
+        let frames_iter: Vec<[u32; CHANNELS]> = expected.as_ref().interleaved_frames().collect();
+        assert_eq!(frames_iter.as_flattened(), interleaved(CHANNELS, frames));
+    }
+}
+
+macro_rules! for_channel_counts {
+    ($($name:ident => $f:ident),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                $f::<1>();
+                $f::<2>();
+                $f::<3>();
+                $f::<4>();
+                $f::<5>();
+                $f::<6>();
+                $f::<7>();
+                $f::<8>();
+            }
+        )*
+    };
+}
+
+for_channel_counts!(
+    const_round_trip => round_trip,
+    const_round_trip_at => round_trip_at,
+    const_constructors => constructors,
+);
+
+#[cfg(feature = "variable-channels")]
+#[test]
+fn var_round_trip() {
+    use audio_channel_buffer::VarChannelBuffer;
+    use core::num::NonZeroUsize;
+
+    for channels in 1..=8 {
+        for frames in FRAME_COUNTS {
+            let channels_nz = NonZeroUsize::new(channels).unwrap();
+
+            let mut src = VarChannelBuffer::<u32, 8>::new(channels_nz, frames);
+            src.as_mut().fill_from_fn(sample);
+
+            for extra in [0, 1, channels - 1] {
+                let mut dst = vec![u32::MAX; frames * channels + extra];
+
+                assert_eq!(src.interleave_from_at(0, &mut dst), frames);
+                assert_eq!(dst[..frames * channels], interleaved(channels, frames));
+                assert!(dst[frames * channels..].iter().all(|&s| s == u32::MAX));
+
+                let mut back = VarChannelBuffer::<u32, 8>::new(channels_nz, frames);
+                assert_eq!(back.deinterleave_into_at(&dst, 0), frames);
+                assert_eq!(back.as_slices(), src.as_slices());
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Tests for the clamping behavior of the range accessors.
+
+use audio_channel_buffer::{ChannelBufferRef, ChannelBufferRefMut};
+
+const FRAMES: usize = 10;
+
+#[allow(clippy::reversed_empty_ranges)]
+const INVERTED: core::ops::Range<usize> = 5..2;
+
+fn all_empty<S: AsRef<[f32]>>(channels: impl IntoIterator<Item = S>) -> bool {
+    channels.into_iter().all(|ch| ch.as_ref().is_empty())
+}
+
+#[test]
+fn const_ref_inverted_range() {
+    let data = [0.0f32; 2 * FRAMES];
+    let buf = ChannelBufferRef::<f32, 2>::new(&data);
+
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(buf.try_as_slices_with_range(INVERTED).is_err());
+}
+
+#[test]
+fn const_ref_mut_inverted_range() {
+    let mut data = [0.0f32; 2 * FRAMES];
+    let mut buf = ChannelBufferRefMut::<f32, 2>::new(&mut data);
+
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(all_empty(buf.as_mut_slices_with_range(INVERTED)));
+    assert!(buf.try_as_slices_with_range(INVERTED).is_err());
+    assert!(buf.try_as_mut_slices_with_range(INVERTED).is_err());
+}
+
+#[test]
+fn const_range_clamped_to_frames() {
+    let data = [0.0f32; 2 * FRAMES];
+    let buf = ChannelBufferRef::<f32, 2>::new(&data);
+
+    assert_eq!(buf.as_slices_with_range(8..20).map(|ch| ch.len()), [2, 2]);
+    assert!(all_empty(buf.as_slices_with_range(12..20)));
+    assert!(buf.try_as_slices_with_range(8..20).is_err());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn const_owned_inverted_range() {
+    use audio_channel_buffer::ChannelBuffer;
+
+    let mut buf = ChannelBuffer::<f32, 2>::new(FRAMES);
+
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(all_empty(buf.as_mut_slices_with_range(INVERTED)));
+    assert!(buf.try_as_slices_with_range(INVERTED).is_err());
+    assert!(buf.try_as_mut_slices_with_range(INVERTED).is_err());
+}
+
+#[cfg(feature = "variable-channels")]
+#[test]
+fn var_ref_inverted_range() {
+    use audio_channel_buffer::{VarChannelBufferRef, VarChannelBufferRefMut};
+    use core::num::NonZeroUsize;
+
+    let channels = NonZeroUsize::new(3).unwrap();
+    let mut data = [0.0f32; 3 * FRAMES];
+
+    let buf = VarChannelBufferRef::<f32, 8>::new(&data, channels);
+    assert_eq!(buf.as_slices_with_range(INVERTED).len(), 3);
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(buf.try_as_slices_with_range(INVERTED).is_err());
+
+    let mut buf = VarChannelBufferRefMut::<f32, 8>::new(&mut data, channels);
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(all_empty(buf.as_mut_slices_with_range(INVERTED)));
+    assert!(buf.try_as_mut_slices_with_range(INVERTED).is_err());
+}
+
+#[cfg(all(feature = "alloc", feature = "variable-channels"))]
+#[test]
+fn var_owned_inverted_range() {
+    use audio_channel_buffer::VarChannelBuffer;
+    use core::num::NonZeroUsize;
+
+    let mut buf = VarChannelBuffer::<f32, 8>::new(NonZeroUsize::new(3).unwrap(), FRAMES);
+
+    assert!(all_empty(buf.as_slices_with_range(INVERTED)));
+    assert!(all_empty(buf.as_mut_slices_with_range(INVERTED)));
+    assert!(buf.try_as_slices_with_range(INVERTED).is_err());
+    assert!(buf.try_as_mut_slices_with_range(INVERTED).is_err());
+}